@@ -0,0 +1,199 @@
+//! simple_units: a simple unit system for Rust
+//!
+//! Written by Willi Kappler, Version 0.1 (2017.02.22)
+//!
+//! Repository: https://github.com/willi-kappler/simple_units
+//!
+//! License: MIT
+//!
+//! Derive macro for new units, the counterpart of *init_unit* and *convert_unit*:
+//!
+//! ```
+//! #[macro_use] extern crate simple_units_derive;
+//! extern crate simple_units;
+//!
+//! use simple_units::si_units::Meter;
+//!
+//! #[derive(Debug, Clone, Copy, Unit)]
+//! #[unit(symbol = "fur", base = Meter, factor = 201.168)]
+//! struct Furlong(f64);
+//!
+//! fn main() {
+//!     assert_eq!(Furlong(2.0) + Furlong(3.0), Furlong(5.0));
+//!     assert_eq!(Meter::from(Furlong(1.0)), Meter(201.168));
+//!     assert_eq!(format!("{}", Furlong(2.5)), "2.5 fur");
+//! }
+//! ```
+//!
+//! Attributes (all optional):
+//!
+//! - *symbol*: the ASCII symbol, also used for Unicode and LaTeX if they are not given
+//! - *unicode*, *latex*: the symbols for the other display styles
+//! - *base*, *factor*: the unit and the size of one new unit expressed in it,
+//!   implements *From*, *PartialEq* and *PartialOrd* in both directions and *FromStr* via the base unit
+//!
+//! *FromStr* always accepts the own symbol of the unit ("2 fur"), with a base unit also all units
+//! of the same dimension ("402.336 m").
+//!
+//! *PartialEq* and *PartialOrd* are implemented by the derive macro, only *Debug*, *Clone* and *Copy* need
+//! to be derived.
+//!
+//! A derived unit can only be converted into and compared with its base unit. Other units of the same
+//! dimension have to be converted into the base first: Furlong(1.0) > Meter::from(Foot(600.0)).
+//!
+//! ```compile_fail
+//! #[macro_use] extern crate simple_units_derive;
+//! extern crate simple_units;
+//!
+//! use simple_units::si_units::Meter;
+//! use simple_units::conversion::Foot;
+//!
+//! #[derive(Debug, Clone, Copy, Unit)]
+//! #[unit(symbol = "fur", base = Meter, factor = 201.168)]
+//! struct Furlong(f64);
+//!
+//! fn main() {
+//!     assert!(Furlong(1.0) > Foot(600.0));
+//! }
+//! ```
+
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use] extern crate quote;
+extern crate syn;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{Data, DeriveInput, Expr, Fields, LitStr, Path, Type};
+use syn::spanned::Spanned;
+
+// Settings from the #[unit(...)] attribute
+#[derive(Default)]
+struct UnitAttributes {
+    symbol: Option<LitStr>,
+    unicode: Option<LitStr>,
+    latex: Option<LitStr>,
+    base: Option<Path>,
+    factor: Option<Expr>,
+}
+
+fn parse_attributes(input: &DeriveInput) -> syn::Result<UnitAttributes> {
+    let mut attributes = UnitAttributes::default();
+
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("unit")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("symbol") {
+                attributes.symbol = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("unicode") {
+                attributes.unicode = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("latex") {
+                attributes.latex = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("base") {
+                attributes.base = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("factor") {
+                attributes.factor = Some(meta.value()?.parse()?);
+            } else {
+                return Err(meta.error("expected symbol, unicode, latex, base or factor"));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(attributes)
+}
+
+// The unit must be a tuple struct with exactly one f64
+fn check_struct(input: &DeriveInput) -> syn::Result<()> {
+    let message = "Unit can only be derived for a tuple struct with one f64: struct Furlong(f64);";
+
+    match input.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => {
+                match fields.unnamed[0].ty {
+                    Type::Path(ref path) if path.path.is_ident("f64") => Ok(()),
+                    ref ty => Err(syn::Error::new(ty.span(), message)),
+                }
+            }
+            _ => Err(syn::Error::new(input.ident.span(), message)),
+        },
+        _ => Err(syn::Error::new(input.ident.span(), message)),
+    }
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    check_struct(input)?;
+    let attributes = parse_attributes(input)?;
+    let unit = &input.ident;
+
+    let ascii = attributes.symbol.unwrap_or_else(|| LitStr::new(&unit.to_string(), unit.span()));
+    let unicode = attributes.unicode.unwrap_or_else(|| ascii.clone());
+    let latex = attributes.latex.unwrap_or_else(|| ascii.clone());
+
+    let arithmetic = quote! {
+        ::simple_units::unit_display!(#unit, #ascii, #unicode, #latex);
+        ::simple_units::unit_impls!(#unit);
+    };
+
+    let conversion = match (attributes.base, attributes.factor) {
+        (Some(base), Some(factor)) => quote! {
+            impl ::std::convert::From<#base> for #unit {
+                fn from(value: #base) -> #unit {
+                    #unit(value.0 / (#factor))
+                }
+            }
+
+            impl ::std::convert::From<#unit> for #base {
+                fn from(value: #unit) -> #base {
+                    #base(value.0 * (#factor))
+                }
+            }
+
+            ::simple_units::compare_unit!(#base, #unit);
+
+            impl ::std::str::FromStr for #unit {
+                type Err = ::simple_units::parse::ParseError;
+
+                fn from_str(input: &str) -> ::std::result::Result<#unit, ::simple_units::parse::ParseError> {
+                    // The parser does not know the own symbols of the unit
+                    let (value, symbol) = ::simple_units::parse::split_quantity(input)?;
+                    if symbol == #ascii || symbol == #unicode {
+                        return ::std::result::Result::Ok(#unit(value));
+                    }
+                    input.parse::<#base>().map(#unit::from)
+                }
+            }
+        },
+        (None, None) => quote! {
+            impl ::std::str::FromStr for #unit {
+                type Err = ::simple_units::parse::ParseError;
+
+                fn from_str(input: &str) -> ::std::result::Result<#unit, ::simple_units::parse::ParseError> {
+                    let (value, symbol) = ::simple_units::parse::split_quantity(input)?;
+                    if symbol == #ascii || symbol == #unicode {
+                        ::std::result::Result::Ok(#unit(value))
+                    } else {
+                        ::std::result::Result::Err(::simple_units::parse::ParseError::UnknownUnit(symbol.to_string()))
+                    }
+                }
+            }
+        },
+        (Some(base), None) => return Err(syn::Error::new(base.span(), "base needs a factor")),
+        (None, Some(factor)) => return Err(syn::Error::new(factor.span(), "factor needs a base")),
+    };
+
+    Ok(quote! {
+        #arithmetic
+        #conversion
+    })
+}
+
+/// Derives a unit from a tuple struct with one f64, see the crate documentation
+#[proc_macro_derive(Unit, attributes(unit))]
+pub fn derive_unit(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
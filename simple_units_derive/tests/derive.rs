@@ -0,0 +1,78 @@
+#[macro_use] extern crate simple_units_derive;
+#[macro_use] extern crate simple_units;
+
+use simple_units::si_units::*;
+use simple_units::conversion::Foot;
+use simple_units::display::{UnitDisplay, DisplayStyle};
+
+#[derive(Debug, Clone, Copy, Unit)]
+#[unit(symbol = "fur", base = Meter, factor = 201.168)]
+struct Furlong(f64);
+
+#[derive(Debug, Clone, Copy, Unit)]
+#[unit(symbol = "ftm", latex = "\\fathom", base = Foot, factor = 6.0)]
+struct Fathom(f64);
+
+#[derive(Debug, Clone, Copy, Unit)]
+struct Widget(f64);
+
+#[test]
+fn test_arithmetic() {
+    assert_eq!(Furlong(2.0) + Furlong(3.0), Furlong(5.0));
+    assert_eq!(Furlong(5.0) - Furlong(3.0), Furlong(2.0));
+    assert_eq!(Furlong(2.0) * 3.0, Furlong(6.0));
+    assert_eq!(3.0 * Furlong(2.0), Furlong(6.0));
+    assert_eq!(Furlong(6.0) / 3.0, Furlong(2.0));
+    assert_eq!(Furlong(6.0) / Furlong(3.0), 2.0);
+    assert_eq!(Furlong(-2.0).abs(), Furlong(2.0));
+}
+
+#[test]
+fn test_conversion() {
+    assert_eq!(Meter::from(Furlong(1.0)), Meter(201.168));
+    assert_eq!(Furlong::from(Meter(402.336)), Furlong(2.0));
+    assert_eq!(Foot::from(Fathom(2.0)), Foot(12.0));
+    assert_eq!("201.168 m".parse::<Furlong>(), Ok(Furlong(1.0)));
+    assert_eq!("12 ft".parse::<Fathom>(), Ok(Fathom(2.0)));
+    assert_unit_eq!(Furlong::from(Meter::from(Foot(660.0))), Furlong(1.0), rel = 1.0e-12);
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(format!("{}", Furlong(2.5)), "2.5 fur");
+    assert_eq!(format!("{}", Fathom(2.5).display_as(DisplayStyle::Latex)), "\\SI{2.5}{\\fathom}");
+    assert_eq!(format!("{}", Fathom(2.5).display_as(DisplayStyle::Unicode)), "2.5 ftm");
+    assert_eq!(format!("{}", Widget(2.5)), "2.5 Widget");
+    assert_eq!(Furlong::display_symbol(DisplayStyle::Ascii), "fur");
+}
+
+#[test]
+fn test_parse_own_symbol() {
+    assert_eq!("2 fur".parse::<Furlong>(), Ok(Furlong(2.0)));
+    assert_eq!("1.5 Widget".parse::<Widget>(), Ok(Widget(1.5)));
+    assert_eq!(format!("{}", Furlong(2.5)).parse::<Furlong>(), Ok(Furlong(2.5)));
+    assert_eq!(format!("{}", Fathom(3.0)).parse::<Fathom>(), Ok(Fathom(3.0)));
+    assert_eq!(format!("{}", Widget(-4.0)).parse::<Widget>(), Ok(Widget(-4.0)));
+    assert_eq!("12 ft".parse::<Fathom>(), Ok(Fathom(2.0)));
+    assert!("2 fur".parse::<Fathom>().is_err());
+    assert!("2 m".parse::<Widget>().is_err());
+}
+
+#[test]
+fn test_compare() {
+    assert!(Furlong(1.0) < Furlong(2.0));
+    assert!(Furlong(1.0) > Meter(200.0));
+    assert!(Meter(201.168) == Furlong(1.0));
+    assert!(Furlong(1.0) > Meter::from(Foot(600.0)));
+    assert!(Meter(200.0) < Furlong(1.0));
+}
+
+#[test]
+fn test_operators() {
+    let mut length = -Furlong(1.0);
+    length += Furlong(3.0);
+    length *= 2.0;
+    assert_eq!(length, Furlong(4.0));
+    assert_eq!(Furlong(5.0) % Furlong(2.0), Furlong(1.0));
+    assert_eq!([Furlong(1.0), Furlong(2.0)].iter().sum::<Furlong>(), Furlong(3.0));
+}
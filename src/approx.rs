@@ -0,0 +1,109 @@
+//! simple_units: a simple unit system for Rust
+//!
+//! Written by Willi Kappler, Version 0.1 (2017.02.22)
+//!
+//! Repository: https://github.com/willi-kappler/simple_units
+//!
+//! License: MIT
+//!
+//! Approximate equality of units with an absolute, relative or ULP tolerance.
+//!
+//! *PartialEq* compares the floating point values exactly, which fails after
+//! most conversions. *ApproxEq* is implemented for every unit:
+//!
+//! ```
+//! # #[macro_use] extern crate simple_units;
+//! # fn main() {
+//! use simple_units::si_units::*;
+//! use simple_units::conversion::*;
+//! use simple_units::approx::ApproxEq;
+//!
+//! let length = Foot(1.0) + Meter(1.0).into();
+//! assert!(length.approx_eq_rel(Foot(4.280839895013123), 1.0e-12));
+//! assert!(length.approx_eq_abs(Foot(4.28), 1.0e-3));
+//!
+//! // Prints both values with their units on failure
+//! assert_unit_eq!(length, Foot(4.280839895013123), rel = 1.0e-12);
+//! assert_unit_eq!(Meter::from(length), Meter(1.3048), ulps = 4);
+//! # }
+//! ```
+
+use std::fmt;
+
+use ::dimension::Dimension;
+use ::display::UnitDisplay;
+use ::quantity::Quantity;
+
+/// Tolerance for *ApproxEq::approx_eq*
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerance {
+    /// Maximal difference, in the unit of the compared values
+    Absolute(f64),
+    /// Maximal difference relative to the larger of both values
+    Relative(f64),
+    /// Maximal number of representable f64 values between both values
+    Ulps(u64),
+}
+
+impl fmt::Display for Tolerance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Tolerance::Absolute(tolerance) => write!(f, "abs = {:e}", tolerance),
+            Tolerance::Relative(tolerance) => write!(f, "rel = {:e}", tolerance),
+            Tolerance::Ulps(ulps) => write!(f, "ulps = {}", ulps),
+        }
+    }
+}
+
+// Maps the bits of a f64 to an integer with the same order, -0.0 and 0.0 are both 0
+fn ordered_bits(value: f64) -> i64 {
+    let bits = value.to_bits() as i64;
+    if bits < 0 {
+        i64::MIN - bits
+    } else {
+        bits
+    }
+}
+
+/// Approximate equality of two numbers. Equal values (also infinities) are always
+/// approximately equal, NaN never
+pub fn approx_eq_f64(lhs: f64, rhs: f64, tolerance: Tolerance) -> bool {
+    if lhs == rhs {
+        return true;
+    }
+
+    if !lhs.is_finite() || !rhs.is_finite() {
+        return false;
+    }
+
+    match tolerance {
+        Tolerance::Absolute(tolerance) => (lhs - rhs).abs() <= tolerance,
+        Tolerance::Relative(tolerance) => (lhs - rhs).abs() <= tolerance * lhs.abs().max(rhs.abs()),
+        Tolerance::Ulps(ulps) => ordered_bits(lhs).abs_diff(ordered_bits(rhs)) <= ulps,
+    }
+}
+
+/// Approximate equality for units, see module *approx*
+pub trait ApproxEq: UnitDisplay + Sized {
+    /// Equality within the given tolerance
+    fn approx_eq(self, other: Self, tolerance: Tolerance) -> bool {
+        approx_eq_f64(self.display_value(), other.display_value(), tolerance)
+    }
+
+    /// Equality within an absolute tolerance, in the unit of self
+    fn approx_eq_abs(self, other: Self, tolerance: f64) -> bool {
+        self.approx_eq(other, Tolerance::Absolute(tolerance))
+    }
+
+    /// Equality within a tolerance relative to the larger value
+    fn approx_eq_rel(self, other: Self, tolerance: f64) -> bool {
+        self.approx_eq(other, Tolerance::Relative(tolerance))
+    }
+
+    /// Equality within the given number of units in the last place
+    fn approx_eq_ulps(self, other: Self, ulps: u64) -> bool {
+        self.approx_eq(other, Tolerance::Ulps(ulps))
+    }
+}
+
+impl<D: Dimension> ApproxEq for Quantity<D> {}
@@ -0,0 +1,196 @@
+//! simple_units: a simple unit system for Rust
+//!
+//! Written by Willi Kappler, Version 0.1 (2017.02.22)
+//!
+//! Repository: https://github.com/willi-kappler/simple_units
+//!
+//! License: MIT
+//!
+//! Physical constants with their units, values from CODATA 2022.
+//!
+//! ```
+//! use simple_units::si_units::*;
+//! use simple_units::constants::{GAS_CONSTANT, BOLTZMANN_CONSTANT, AVOGADRO_CONSTANT};
+//!
+//! let r: JoulePerKelvinMol = BOLTZMANN_CONSTANT.value * AVOGADRO_CONSTANT.value;
+//! assert!((r - GAS_CONSTANT.value).abs().0 < 1.0e-12);
+//! ```
+//!
+//! Older releases are available as sets with the common trait *ConstantSet*
+//! (*Codata2006*, *Codata2010*, *Codata2014*, *Codata2018*, *Codata2022*),
+//! to reproduce published results with the values they used:
+//!
+//! ```
+//! use simple_units::si_units::*;
+//! use simple_units::constants::{ConstantSet, Codata2014};
+//!
+//! fn thermal_energy<C: ConstantSet>(temperature: KelvinDelta) -> JoulePerMol {
+//!     C::GAS_CONSTANT.value * temperature
+//! }
+//!
+//! assert_eq!(Codata2014::GAS_CONSTANT.value, JoulePerKelvinMol(8.3144598));
+//! let energy = thermal_energy::<Codata2014>(KelvinDelta(300.0));
+//! ```
+
+use ::quantity::Quantity;
+use ::si_units::*;
+
+/// A physical constant with its standard uncertainty
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Constant<U> {
+    /// Name of the constant: "molar gas constant"
+    pub name: &'static str,
+    /// Recommended value
+    pub value: U,
+    /// Standard uncertainty, zero for exact constants
+    pub uncertainty: U,
+    /// CODATA release the value is taken from: "CODATA 2022"
+    pub release: &'static str,
+}
+
+impl<D> Constant<Quantity<D>> {
+    /// The value is exact by definition of the SI
+    pub fn is_exact(&self) -> bool {
+        self.uncertainty.0 == 0.0
+    }
+
+    /// Standard uncertainty relative to the value
+    pub fn relative_uncertainty(&self) -> f64 {
+        (self.uncertainty.0 / self.value.0).abs()
+    }
+}
+
+// Constant with a standard uncertainty, zero for exact constants
+const fn constant<U>(name: &'static str, value: U, uncertainty: U, release: &'static str) -> Constant<U> {
+    Constant { name, value, uncertainty, release }
+}
+
+/// A set of constants from one CODATA release
+pub trait ConstantSet {
+    /// Name of the release: "CODATA 2022"
+    const RELEASE: &'static str;
+    /// Speed of light in vacuum, c
+    const SPEED_OF_LIGHT: Constant<MeterPerSecond>;
+    /// Planck constant, h
+    const PLANCK_CONSTANT: Constant<JouleSecond>;
+    /// Elementary charge, e
+    const ELEMENTARY_CHARGE: Constant<Coulomb>;
+    /// Boltzmann constant, k
+    const BOLTZMANN_CONSTANT: Constant<JoulePerKelvin>;
+    /// Avogadro constant, N_A
+    const AVOGADRO_CONSTANT: Constant<PerMol>;
+    /// Molar gas constant, R = N_A k
+    const GAS_CONSTANT: Constant<JoulePerKelvinMol>;
+    /// Newtonian constant of gravitation, G
+    const GRAVITATIONAL_CONSTANT: Constant<Meter3PerKilogramSecond2>;
+    /// Standard acceleration of gravity, g_n
+    const STANDARD_GRAVITY: Constant<MeterPerSecond2>;
+}
+
+// Each constant is given as (value, standard uncertainty)
+macro_rules! codata_set {
+    ($set:ident, $release:expr, c = $c:expr, h = $h:expr, e = $e:expr, k = $k:expr,
+     N_A = $n_a:expr, R = $r:expr, G = $g:expr, g_n = $g_n:expr) => {
+        #[doc = $release]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $set;
+
+        impl ConstantSet for $set {
+            const RELEASE: &'static str = $release;
+            const SPEED_OF_LIGHT: Constant<MeterPerSecond> =
+                constant("speed of light in vacuum", MeterPerSecond($c.0), MeterPerSecond($c.1), $release);
+            const PLANCK_CONSTANT: Constant<JouleSecond> =
+                constant("Planck constant", JouleSecond($h.0), JouleSecond($h.1), $release);
+            const ELEMENTARY_CHARGE: Constant<Coulomb> =
+                constant("elementary charge", Coulomb($e.0), Coulomb($e.1), $release);
+            const BOLTZMANN_CONSTANT: Constant<JoulePerKelvin> =
+                constant("Boltzmann constant", JoulePerKelvin($k.0), JoulePerKelvin($k.1), $release);
+            const AVOGADRO_CONSTANT: Constant<PerMol> =
+                constant("Avogadro constant", PerMol($n_a.0), PerMol($n_a.1), $release);
+            const GAS_CONSTANT: Constant<JoulePerKelvinMol> =
+                constant("molar gas constant", JoulePerKelvinMol($r.0), JoulePerKelvinMol($r.1), $release);
+            const GRAVITATIONAL_CONSTANT: Constant<Meter3PerKilogramSecond2> =
+                constant("Newtonian constant of gravitation", Meter3PerKilogramSecond2($g.0), Meter3PerKilogramSecond2($g.1), $release);
+            const STANDARD_GRAVITY: Constant<MeterPerSecond2> =
+                constant("standard acceleration of gravity", MeterPerSecond2($g_n.0), MeterPerSecond2($g_n.1), $release);
+        }
+    };
+}
+
+codata_set!(Codata2006, "CODATA 2006",
+    c = (299792458.0, 0.0),
+    h = (6.62606896e-34, 0.00000033e-34),
+    e = (1.602176487e-19, 0.000000040e-19),
+    k = (1.3806504e-23, 0.0000024e-23),
+    N_A = (6.02214179e23, 0.00000030e23),
+    R = (8.314472, 0.000015),
+    G = (6.67428e-11, 0.00067e-11),
+    g_n = (9.80665, 0.0));
+
+codata_set!(Codata2010, "CODATA 2010",
+    c = (299792458.0, 0.0),
+    h = (6.62606957e-34, 0.00000029e-34),
+    e = (1.602176565e-19, 0.000000035e-19),
+    k = (1.3806488e-23, 0.0000013e-23),
+    N_A = (6.02214129e23, 0.00000027e23),
+    R = (8.3144621, 0.0000075),
+    G = (6.67384e-11, 0.00080e-11),
+    g_n = (9.80665, 0.0));
+
+codata_set!(Codata2014, "CODATA 2014",
+    c = (299792458.0, 0.0),
+    h = (6.626070040e-34, 0.000000081e-34),
+    e = (1.6021766208e-19, 0.0000000098e-19),
+    k = (1.38064852e-23, 0.00000079e-23),
+    N_A = (6.022140857e23, 0.000000074e23),
+    R = (8.3144598, 0.0000048),
+    G = (6.67408e-11, 0.00031e-11),
+    g_n = (9.80665, 0.0));
+
+// Since the revision of the SI in 2019 h, e, k and N_A (and so R) are exact
+codata_set!(Codata2018, "CODATA 2018",
+    c = (299792458.0, 0.0),
+    h = (6.62607015e-34, 0.0),
+    e = (1.602176634e-19, 0.0),
+    k = (1.380649e-23, 0.0),
+    N_A = (6.02214076e23, 0.0),
+    R = (8.31446261815324, 0.0),
+    G = (6.67430e-11, 0.00015e-11),
+    g_n = (9.80665, 0.0));
+
+codata_set!(Codata2022, "CODATA 2022",
+    c = (299792458.0, 0.0),
+    h = (6.62607015e-34, 0.0),
+    e = (1.602176634e-19, 0.0),
+    k = (1.380649e-23, 0.0),
+    N_A = (6.02214076e23, 0.0),
+    R = (8.31446261815324, 0.0),
+    G = (6.67430e-11, 0.00015e-11),
+    g_n = (9.80665, 0.0));
+
+/// The CODATA release of the constants in this module
+pub const CODATA_RELEASE: &str = Codata2022::RELEASE;
+
+/// Speed of light in vacuum, c
+pub const SPEED_OF_LIGHT: Constant<MeterPerSecond> = Codata2022::SPEED_OF_LIGHT;
+
+/// Planck constant, h
+pub const PLANCK_CONSTANT: Constant<JouleSecond> = Codata2022::PLANCK_CONSTANT;
+
+/// Elementary charge, e
+pub const ELEMENTARY_CHARGE: Constant<Coulomb> = Codata2022::ELEMENTARY_CHARGE;
+
+/// Boltzmann constant, k
+pub const BOLTZMANN_CONSTANT: Constant<JoulePerKelvin> = Codata2022::BOLTZMANN_CONSTANT;
+
+/// Avogadro constant, N_A
+pub const AVOGADRO_CONSTANT: Constant<PerMol> = Codata2022::AVOGADRO_CONSTANT;
+
+/// Molar gas constant, R = N_A k
+pub const GAS_CONSTANT: Constant<JoulePerKelvinMol> = Codata2022::GAS_CONSTANT;
+
+/// Newtonian constant of gravitation, G
+pub const GRAVITATIONAL_CONSTANT: Constant<Meter3PerKilogramSecond2> = Codata2022::GRAVITATIONAL_CONSTANT;
+
+/// Standard acceleration of gravity, g_n
+pub const STANDARD_GRAVITY: Constant<MeterPerSecond2> = Codata2022::STANDARD_GRAVITY;
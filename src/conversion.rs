@@ -8,86 +8,234 @@
 //!
 //! Conversion between units
 
-use std::ops::Add;
-use std::ops::Sub;
-use std::ops::Mul;
-use std::ops::Div;
-use std::cmp::PartialEq;
+use std::f64::consts::PI;
 
 use ::si_units::*;
-
-// TODO: move all constants to https://github.com/willi-kappler/natural_constants
-
-const METER_TO_FOOT: f64 = 3.28084;
-const METER_TO_YARD: f64 = 1.09361;
-const METER_TO_MILE: f64 = 0.000621371;
-
-const METER_TO_KILOMETER: f64 = 1.0e-3;
-const METER_TO_MILIMETER: f64 = 1.0e+3;
-const METER_TO_MICROMETER: f64 = 1.0e+6;
-const METER_TO_NANOMETER: f64 = 1.0e+9;
-const METER_TO_ASTRONOMICALUNIT: f64 = 1.0 / 149597870700.0;
-const METER_TO_LIGHTYEAR: f64 =  1.0 / 9.4607e15;
-const METER_TO_PARSEC: f64 = 1.0 / 3.08567758149137e16;
-
-const SECOND_TO_MINUTE: f64 = 60.0;
-const SECOND_TO_HOUR: f64 = 60.0 * 60.0;
-const SECOND_TO_DAY: f64 = 60.0 * 60.0 * 24.0;
-const SECOND_TO_WEEK: f64 = SECOND_TO_DAY * 7.0;
-const SECOND_TO_MONTH: f64 = SECOND_TO_DAY * 30.0; // simple
-const SECOND_TO_MONTH_AVG: f64 = SECOND_TO_DAY * 30.42; // average
-const SECOND_TO_MONTH_AVG_LEAP: f64 = SECOND_TO_DAY * 30.50; // average leap year
-const SECOND_TO_MONTH_SOLAR: f64 = SECOND_TO_DAY * 30.44; // solar calendar
-const SECOND_TO_MONTH_LUNAR: f64 = SECOND_TO_DAY * 29.53; // lunar month
-
-const SECOND_TO_YEAR: f64 = SECOND_TO_DAY * 365.25; // on average
-const SECOND_TO_MIL_YEAR: f64 = SECOND_TO_YEAR * 1.0e6;
-
-const DEGC_TO_KELVIN: f64 = 273.15;
-
-init_unit!(Foot);
-init_unit!(Yard);
-init_unit!(Mile);
-
-convert_unit!(Meter, Foot, METER_TO_FOOT);
-convert_unit!(Meter, Yard, METER_TO_YARD);
-convert_unit!(Meter, Mile, METER_TO_MILE);
-
-init_unit!(Kilometer);
-init_unit!(Milimeter);
-init_unit!(Micrometer);
-init_unit!(Nanometer);
-
-convert_unit!(Meter, Kilometer, METER_TO_KILOMETER);
-convert_unit!(Meter, Milimeter, METER_TO_MILIMETER);
-convert_unit!(Meter, Micrometer, METER_TO_MICROMETER);
-convert_unit!(Meter, Nanometer, METER_TO_NANOMETER);
-
-init_unit!(AstronomicalUnit);
-init_unit!(LightYear);
-init_unit!(Parsec);
-
-convert_unit!(Meter, AstronomicalUnit, METER_TO_ASTRONOMICALUNIT);
-convert_unit!(Meter, LightYear, METER_TO_LIGHTYEAR);
-convert_unit!(Meter, Parsec, METER_TO_PARSEC);
-
-init_unit!(Minute);
-init_unit!(Hour);
-init_unit!(Day);
-init_unit!(Week);
-init_unit!(Month);
-init_unit!(Year);
-init_unit!(MilYear);
-
-convert_unit!(Second, Minute, SECOND_TO_MINUTE);
-convert_unit!(Second, Hour, SECOND_TO_HOUR);
-convert_unit!(Second, Day, SECOND_TO_DAY);
-convert_unit!(Second, Week, SECOND_TO_WEEK);
-convert_unit!(Second, Month, SECOND_TO_MONTH);
-convert_unit!(Second, Year, SECOND_TO_YEAR);
-convert_unit!(Second, MilYear, SECOND_TO_MIL_YEAR);
-
-init_unit!(Fahrenheit);
+use ::prefix::*;
+use ::display::UnitDisplay;
+use ::quantity::Quantity;
+
+/// Conversion with a turbofish, available on every unit:
+/// Meter(1.0) + Foot(3.28).to::<Meter>()
+pub trait Convert: Sized {
+    /// Converts into the unit U, like *into* but the target is given explicitly
+    fn to<U: From<Self>>(self) -> U {
+        U::from(self)
+    }
+
+    /// The value of this unit expressed in the unit U:
+    /// Mile(1.0).in_units_of::<Foot>() == 5280.0
+    fn in_units_of<U: From<Self> + UnitDisplay>(self) -> f64 {
+        U::from(self).display_value()
+    }
+}
+
+impl<D> Convert for Quantity<D> {}
+
+// Every constant is the size of one unit expressed in the SI base unit,
+// physical constants are in module *constants*
+
+pub(crate) const FOOT_IN_METERS: f64 = 0.3048;
+pub(crate) const YARD_IN_METERS: f64 = 0.9144;
+pub(crate) const MILE_IN_METERS: f64 = 1609.344;
+
+pub(crate) const ASTRONOMICALUNIT_IN_METERS: f64 = 149597870700.0;
+pub(crate) const LIGHTYEAR_IN_METERS: f64 = 9.4607e15;
+pub(crate) const PARSEC_IN_METERS: f64 = 3.08567758149137e16;
+
+pub(crate) const MINUTE_IN_SECONDS: f64 = 60.0;
+pub(crate) const HOUR_IN_SECONDS: f64 = 60.0 * 60.0;
+pub(crate) const DAY_IN_SECONDS: f64 = 60.0 * 60.0 * 24.0;
+pub(crate) const WEEK_IN_SECONDS: f64 = DAY_IN_SECONDS * 7.0;
+pub(crate) const MONTH_IN_SECONDS: f64 = DAY_IN_SECONDS * 30.0; // simple
+pub(crate) const MONTH_AVG_IN_SECONDS: f64 = DAY_IN_SECONDS * 30.42; // average
+pub(crate) const MONTH_AVG_LEAP_IN_SECONDS: f64 = DAY_IN_SECONDS * 30.50; // average leap year
+pub(crate) const MONTH_SOLAR_IN_SECONDS: f64 = DAY_IN_SECONDS * 30.44; // solar calendar
+pub(crate) const MONTH_LUNAR_IN_SECONDS: f64 = DAY_IN_SECONDS * 29.53; // lunar month
+
+pub(crate) const YEAR_IN_SECONDS: f64 = DAY_IN_SECONDS * 365.25; // on average
+pub(crate) const MIL_YEAR_IN_SECONDS: f64 = YEAR_IN_SECONDS * 1.0e6;
+
+pub(crate) const DEGC_TO_KELVIN: f64 = 273.15;
+pub(crate) const FAHRENHEIT_ZERO_IN_KELVIN: f64 = 459.67 * 5.0 / 9.0;
+
+pub(crate) const DEGREE_IN_RADIANS: f64 = PI / 180.0;
+pub(crate) const GRADIAN_IN_RADIANS: f64 = PI / 200.0;
+pub(crate) const ARCMINUTE_IN_RADIANS: f64 = DEGREE_IN_RADIANS / 60.0;
+pub(crate) const ARCSECOND_IN_RADIANS: f64 = ARCMINUTE_IN_RADIANS / 60.0;
+
+// Temperature differences only need a scale factor, no offset
+pub(crate) const DEGC_DELTA_IN_KELVIN: f64 = 1.0;
+pub(crate) const FAHRENHEIT_DELTA_IN_KELVIN: f64 = 5.0 / 9.0;
+pub(crate) const FAHRENHEIT_DELTA_IN_DEGC: f64 = 5.0 / 9.0;
+
+init_unit!(Foot, "ft");
+init_unit!(Yard, "yd");
+init_unit!(Mile, "mi");
+
+convert_unit!(Meter, Foot, size = FOOT_IN_METERS);
+convert_unit!(Meter, Yard, size = YARD_IN_METERS);
+convert_unit!(Meter, Mile, size = MILE_IN_METERS);
+
+prefix_unit!(Meter, Kilometer, KILO, "km", "km", "\\kilo\\metre");
+prefix_unit!(Meter, Centimeter, CENTI, "cm", "cm", "\\centi\\metre");
+prefix_unit!(Meter, Milimeter, MILLI, "mm", "mm", "\\milli\\metre");
+prefix_unit!(Meter, Micrometer, MICRO, "um", "µm", "\\micro\\metre");
+prefix_unit!(Meter, Nanometer, NANO, "nm", "nm", "\\nano\\metre");
+
+init_unit!(AstronomicalUnit, "au", "au", "\\astronomicalunit");
+init_unit!(LightYear, "ly");
+init_unit!(Parsec, "pc");
+
+convert_unit!(Meter, AstronomicalUnit, size = ASTRONOMICALUNIT_IN_METERS);
+convert_unit!(Meter, LightYear, size = LIGHTYEAR_IN_METERS);
+convert_unit!(Meter, Parsec, size = PARSEC_IN_METERS);
+
+init_unit!(Degree, "deg", "°", "\\degree");
+init_unit!(Gradian, "gon");
+init_unit!(ArcMinute, "arcmin", "′", "\\arcminute");
+init_unit!(ArcSecond, "arcsec", "″", "\\arcsecond");
+
+convert_unit!(Radian, Degree, size = DEGREE_IN_RADIANS);
+convert_unit!(Radian, Gradian, size = GRADIAN_IN_RADIANS);
+convert_unit!(Radian, ArcMinute, size = ARCMINUTE_IN_RADIANS);
+convert_unit!(Radian, ArcSecond, size = ARCSECOND_IN_RADIANS);
+
+angle_unit!(Degree);
+angle_unit!(Gradian);
+angle_unit!(ArcMinute);
+angle_unit!(ArcSecond);
+
+init_unit!(Minute, "min", "min", "\\minute");
+init_unit!(Hour, "h", "h", "\\hour");
+init_unit!(Day, "d", "d", "\\day");
+init_unit!(Week, "wk");
+init_unit!(Month, "month");
+init_unit!(Year, "a");
+init_unit!(MilYear, "Ma");
+
+convert_unit!(Second, Minute, size = MINUTE_IN_SECONDS);
+convert_unit!(Second, Hour, size = HOUR_IN_SECONDS);
+convert_unit!(Second, Day, size = DAY_IN_SECONDS);
+convert_unit!(Second, Week, size = WEEK_IN_SECONDS);
+convert_unit!(Second, Month, size = MONTH_IN_SECONDS);
+convert_unit!(Second, Year, size = YEAR_IN_SECONDS);
+convert_unit!(Second, MilYear, size = MIL_YEAR_IN_SECONDS);
+
+prefix_unit!(Second, Millisecond, MILLI, "ms", "ms", "\\milli\\second");
+prefix_unit!(Second, Microsecond, MICRO, "us", "µs", "\\micro\\second");
+prefix_unit!(Second, Nanosecond, NANO, "ns", "ns", "\\nano\\second");
+
+// The prefixes for mass are applied to the gram, not to the kilogram
+prefix_unit!(Kilogram, Gram, GRAM, "g", "g", "\\gram");
+prefix_unit!(Kilogram, Milligram, MILLI * GRAM, "mg", "mg", "\\milli\\gram");
+prefix_unit!(Kilogram, Microgram, MICRO * GRAM, "ug", "µg", "\\micro\\gram");
+prefix_unit!(Kilogram, Tonne, MEGA * GRAM, "t", "t", "\\tonne");
+
+prefix_unit!(Pascal, Hectopascal, HECTO, "hPa", "hPa", "\\hecto\\pascal");
+prefix_unit!(Pascal, Kilopascal, KILO, "kPa", "kPa", "\\kilo\\pascal");
+prefix_unit!(Pascal, Megapascal, MEGA, "MPa", "MPa", "\\mega\\pascal");
+prefix_unit!(Pascal, Gigapascal, GIGA, "GPa", "GPa", "\\giga\\pascal");
+
+prefix_unit!(Joule, Kilojoule, KILO, "kJ", "kJ", "\\kilo\\joule");
+prefix_unit!(Joule, Megajoule, MEGA, "MJ", "MJ", "\\mega\\joule");
+
+prefix_unit!(Watt, Kilowatt, KILO, "kW", "kW", "\\kilo\\watt");
+prefix_unit!(Watt, Megawatt, MEGA, "MW", "MW", "\\mega\\watt");
+
+init_unit!(FahrenheitDelta, "degF", "°F", "\\degree{}F");
+init_absolute_unit!(Fahrenheit, FahrenheitDelta, "degF", "°F", "\\degree{}F");
 
 convert_unit!(DegC, Kelvin, |value| {value + DEGC_TO_KELVIN}, |value| {value - DEGC_TO_KELVIN});
 convert_unit!(DegC, Fahrenheit, |value| {(value * 1.8) + 32.0}, |value| {(value - 32.0) / 1.8});
+convert_unit!(Kelvin, Fahrenheit,
+    |value| {(value - FAHRENHEIT_ZERO_IN_KELVIN) / FAHRENHEIT_DELTA_IN_KELVIN},
+    |value| {(value * FAHRENHEIT_DELTA_IN_KELVIN) + FAHRENHEIT_ZERO_IN_KELVIN});
+
+convert_unit!(KelvinDelta, DegCDelta, size = DEGC_DELTA_IN_KELVIN);
+convert_unit!(KelvinDelta, FahrenheitDelta, size = FAHRENHEIT_DELTA_IN_KELVIN);
+convert_unit!(DegCDelta, FahrenheitDelta, size = FAHRENHEIT_DELTA_IN_DEGC);
+
+convert_unit!(KelvinPerSecond, DegCPerSecond, size = DEGC_DELTA_IN_KELVIN);
+
+compare_unit!(DegC, Kelvin);
+compare_unit!(DegC, Fahrenheit);
+compare_unit!(Kelvin, Fahrenheit);
+compare_unit!(KelvinDelta, DegCDelta);
+compare_unit!(KelvinDelta, FahrenheitDelta);
+compare_unit!(DegCDelta, FahrenheitDelta);
+compare_unit!(KelvinPerSecond, DegCPerSecond);
+
+// Direct conversion and comparison between all units of the same dimension,
+// the temperatures above are already converted and compared pairwise
+convert_via_base!(Meter, Foot, Yard, Mile, Kilometer, Centimeter, Milimeter, Micrometer, Nanometer,
+    AstronomicalUnit, LightYear, Parsec);
+convert_via_base!(Second, Minute, Hour, Day, Week, Month, Year, MilYear,
+    Millisecond, Microsecond, Nanosecond);
+convert_via_base!(Kilogram, Gram, Milligram, Microgram, Tonne);
+convert_via_base!(Pascal, Hectopascal, Kilopascal, Megapascal, Gigapascal);
+convert_via_base!(Joule, Kilojoule, Megajoule);
+convert_via_base!(Watt, Kilowatt, Megawatt);
+convert_via_base!(Radian, Degree, Gradian, ArcMinute, ArcSecond);
+
+parse_unit!(Radian, Unitless, "rad");
+parse_unit!(Steradian, Unitless, "sr");
+parse_unit!(Degree, Radian);
+parse_unit!(Hertz, PerSecond, "Hz");
+parse_unit!(Becquerel, PerSecond, "Bq");
+parse_unit!(RadianPerSecond, PerSecond, "rad");
+parse_unit!(Gray, JoulePerKilogram, "Gy");
+parse_unit!(Sievert, JoulePerKilogram, "Sv");
+parse_unit!(Katal, MolPerSecond, "kat");
+parse_unit!(NewtonMeter, Joule);
+parse_unit!(Barn, Meter2);
+parse_unit!(Gradian, Radian);
+parse_unit!(ArcMinute, Radian);
+parse_unit!(ArcSecond, Radian);
+
+parse_unit!(Foot, Meter);
+parse_unit!(Yard, Meter);
+parse_unit!(Mile, Meter);
+parse_unit!(Kilometer, Meter);
+parse_unit!(Centimeter, Meter);
+parse_unit!(Milimeter, Meter);
+parse_unit!(Micrometer, Meter);
+parse_unit!(Nanometer, Meter);
+parse_unit!(AstronomicalUnit, Meter);
+parse_unit!(LightYear, Meter);
+parse_unit!(Parsec, Meter);
+
+parse_unit!(Minute, Second);
+parse_unit!(Hour, Second);
+parse_unit!(Day, Second);
+parse_unit!(Week, Second);
+parse_unit!(Month, Second);
+parse_unit!(Year, Second);
+parse_unit!(MilYear, Second);
+parse_unit!(Millisecond, Second);
+parse_unit!(Microsecond, Second);
+parse_unit!(Nanosecond, Second);
+
+parse_unit!(Gram, Kilogram);
+parse_unit!(Milligram, Kilogram);
+parse_unit!(Microgram, Kilogram);
+parse_unit!(Tonne, Kilogram);
+
+parse_unit!(Hectopascal, Pascal);
+parse_unit!(Kilopascal, Pascal);
+parse_unit!(Megapascal, Pascal);
+parse_unit!(Gigapascal, Pascal);
+
+parse_unit!(Kilojoule, Joule);
+parse_unit!(Megajoule, Joule);
+
+parse_unit!(Kilowatt, Watt);
+parse_unit!(Megawatt, Watt);
+
+// Absolute temperatures are parsed into Kelvin, so the offset is applied
+parse_unit!(DegC, Kelvin);
+parse_unit!(Fahrenheit, Kelvin);
+
+parse_unit!(DegCDelta, KelvinDelta);
+parse_unit!(FahrenheitDelta, KelvinDelta);
+parse_unit!(DegCPerSecond, KelvinPerSecond);
@@ -0,0 +1,134 @@
+//! simple_units: a simple unit system for Rust
+//!
+//! Written by Willi Kappler, Version 0.1 (2017.02.22)
+//!
+//! Repository: https://github.com/willi-kappler/simple_units
+//!
+//! License: MIT
+//!
+//! Type-level dimensions: a dimension is a vector of seven integer exponents,
+//! one for each SI base quantity, encoded with typenum integers.
+
+use std::marker::PhantomData;
+use std::ops::Add;
+use std::ops::Sub;
+use std::ops::Neg;
+use std::ops::Mul;
+
+use typenum::{Integer, PartialDiv};
+use typenum::operator_aliases::{Sum, Diff, Negate, Prod, PartialQuot};
+
+// Exponents used to spell out dimensions, see *init_quantity*
+pub use typenum::{N6, N5, N4, N3, N2, N1, Z0, P1, P2, P3, P4, P5, P6};
+
+/// The dimension L^l * M^m * T^t * Θ^th * N^n * I^i * J^j
+///
+/// Exponent order: length, mass, time, temperature, amount of substance,
+/// electric current, luminous intensity.
+/// This type only exists at the type level and is never constructed.
+pub struct Dim<L, M, T, Th, N, I, J>(PhantomData<(L, M, T, Th, N, I, J)>);
+
+/// Number of SI base quantities
+pub const BASE_QUANTITIES: usize = 7;
+
+/// Runtime access to the exponents of a dimension
+pub trait Dimension {
+    /// Exponents in the order length, mass, time, temperature, amount, current, luminosity
+    const EXPONENTS: [i32; BASE_QUANTITIES];
+}
+
+impl<L, M, T, Th, N, I, J> Dimension for Dim<L, M, T, Th, N, I, J>
+    where L: Integer, M: Integer, T: Integer, Th: Integer, N: Integer, I: Integer, J: Integer {
+    const EXPONENTS: [i32; BASE_QUANTITIES] = [L::I32, M::I32, T::I32, Th::I32, N::I32, I::I32, J::I32];
+}
+
+/// Dimension of a product: the exponents are added
+pub trait DimMul<Rhs> {
+    type Output;
+}
+
+/// Dimension of a quotient: the exponents are subtracted
+pub trait DimDiv<Rhs> {
+    type Output;
+}
+
+/// Dimension of the inverse: the exponents are negated
+pub trait DimInv {
+    type Output;
+}
+
+impl<La, Ma, Ta, Tha, Na, Ia, Ja, Lb, Mb, Tb, Thb, Nb, Ib, Jb> DimMul<Dim<Lb, Mb, Tb, Thb, Nb, Ib, Jb>> for Dim<La, Ma, Ta, Tha, Na, Ia, Ja>
+    where La: Add<Lb>, Ma: Add<Mb>, Ta: Add<Tb>, Tha: Add<Thb>, Na: Add<Nb>, Ia: Add<Ib>, Ja: Add<Jb> {
+    type Output = Dim<Sum<La, Lb>, Sum<Ma, Mb>, Sum<Ta, Tb>, Sum<Tha, Thb>, Sum<Na, Nb>, Sum<Ia, Ib>, Sum<Ja, Jb>>;
+}
+
+impl<La, Ma, Ta, Tha, Na, Ia, Ja, Lb, Mb, Tb, Thb, Nb, Ib, Jb> DimDiv<Dim<Lb, Mb, Tb, Thb, Nb, Ib, Jb>> for Dim<La, Ma, Ta, Tha, Na, Ia, Ja>
+    where La: Sub<Lb>, Ma: Sub<Mb>, Ta: Sub<Tb>, Tha: Sub<Thb>, Na: Sub<Nb>, Ia: Sub<Ib>, Ja: Sub<Jb> {
+    type Output = Dim<Diff<La, Lb>, Diff<Ma, Mb>, Diff<Ta, Tb>, Diff<Tha, Thb>, Diff<Na, Nb>, Diff<Ia, Ib>, Diff<Ja, Jb>>;
+}
+
+impl<L, M, T, Th, N, I, J> DimInv for Dim<L, M, T, Th, N, I, J>
+    where L: Neg, M: Neg, T: Neg, Th: Neg, N: Neg, I: Neg, J: Neg {
+    type Output = Dim<Negate<L>, Negate<M>, Negate<T>, Negate<Th>, Negate<N>, Negate<I>, Negate<J>>;
+}
+
+/// Dimension of the N-th power: the exponents are multiplied by N
+pub trait DimPow<N> {
+    type Output;
+}
+
+/// Dimension of the N-th root: the exponents are divided by N.
+/// Only implemented if all exponents are divisible by N.
+pub trait DimRoot<N> {
+    type Output;
+}
+
+impl<L, M, T, Th, N, I, J, E> DimPow<E> for Dim<L, M, T, Th, N, I, J>
+    where L: Mul<E>, M: Mul<E>, T: Mul<E>, Th: Mul<E>, N: Mul<E>, I: Mul<E>, J: Mul<E> {
+    type Output = Dim<Prod<L, E>, Prod<M, E>, Prod<T, E>, Prod<Th, E>, Prod<N, E>, Prod<I, E>, Prod<J, E>>;
+}
+
+impl<L, M, T, Th, N, I, J, E> DimRoot<E> for Dim<L, M, T, Th, N, I, J>
+    where L: PartialDiv<E>, M: PartialDiv<E>, T: PartialDiv<E>, Th: PartialDiv<E>, N: PartialDiv<E>, I: PartialDiv<E>, J: PartialDiv<E> {
+    type Output = Dim<PartialQuot<L, E>, PartialQuot<M, E>, PartialQuot<T, E>, PartialQuot<Th, E>,
+        PartialQuot<N, E>, PartialQuot<I, E>, PartialQuot<J, E>>;
+}
+
+/// Dimension of A * B
+pub type DimProd<A, B> = <A as DimMul<B>>::Output;
+
+/// Dimension of A / B
+pub type DimQuot<A, B> = <A as DimDiv<B>>::Output;
+
+/// Dimension of 1 / A
+pub type DimInverse<A> = <A as DimInv>::Output;
+
+/// Dimension of A^N
+pub type DimPower<A, N> = <A as DimPow<N>>::Output;
+
+/// Dimension of the N-th root of A
+pub type DimRootOf<A, N> = <A as DimRoot<N>>::Output;
+
+/// All exponents are zero
+pub type Dimensionless = Dim<Z0, Z0, Z0, Z0, Z0, Z0, Z0>;
+
+/// SI base quantity length (meter)
+pub type Length = Dim<P1, Z0, Z0, Z0, Z0, Z0, Z0>;
+
+/// SI base quantity mass (kilogram)
+pub type Mass = Dim<Z0, P1, Z0, Z0, Z0, Z0, Z0>;
+
+/// SI base quantity time (second)
+pub type Time = Dim<Z0, Z0, P1, Z0, Z0, Z0, Z0>;
+
+/// SI base quantity thermodynamic temperature (kelvin)
+pub type Temperature = Dim<Z0, Z0, Z0, P1, Z0, Z0, Z0>;
+
+/// SI base quantity amount of substance (mol)
+pub type Amount = Dim<Z0, Z0, Z0, Z0, P1, Z0, Z0>;
+
+/// SI base quantity electric current (ampere)
+pub type Current = Dim<Z0, Z0, Z0, Z0, Z0, P1, Z0>;
+
+/// SI base quantity luminous intensity (candela)
+pub type Luminosity = Dim<Z0, Z0, Z0, Z0, Z0, Z0, P1>;
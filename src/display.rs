@@ -0,0 +1,277 @@
+//! simple_units: a simple unit system for Rust
+//!
+//! Written by Willi Kappler, Version 0.1 (2017.02.22)
+//!
+//! Repository: https://github.com/willi-kappler/simple_units
+//!
+//! License: MIT
+//!
+//! Human readable output of units with their symbols.
+//!
+//! *Display* uses the Unicode style ("2e-13 m²·s⁻¹"), other styles can be selected
+//! with *display_as*. Width, fill, alignment, precision, sign and the exponent
+//! notation ("{:e}", "{:E}") are honored.
+
+use std::fmt;
+
+use ::dimension::{Dimension, BASE_QUANTITIES};
+use ::quantity::Quantity;
+
+/// Output style for units
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayStyle {
+    /// Plain ASCII: "2e-13 m^2/s"
+    Ascii,
+    /// Unicode superscripts: "2e-13 m²·s⁻¹"
+    Unicode,
+    /// LaTeX with the siunitx package: "\SI{2e-13}{\metre\squared\per\second}"
+    Latex,
+}
+
+/// Number notation, selected by the formatting trait
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Notation {
+    Decimal,
+    LowerExp,
+    UpperExp,
+}
+
+/// A unit that can be displayed with its symbol
+pub trait UnitDisplay {
+    /// The value in this unit
+    fn display_value(&self) -> f64;
+
+    /// The symbol of this unit in the given style
+    fn display_symbol(style: DisplayStyle) -> String;
+
+    /// Display this unit in the given style:
+    /// format!("{:e}", Meter(2.0).display_as(DisplayStyle::Latex))
+    fn display_as(&self, style: DisplayStyle) -> Styled<'_, Self> where Self: Sized {
+        Styled { unit: self, style }
+    }
+}
+
+/// A unit together with the output style, see *UnitDisplay::display_as*
+pub struct Styled<'a, U: 'a> {
+    unit: &'a U,
+    style: DisplayStyle,
+}
+
+impl<'a, U: UnitDisplay> fmt::Display for Styled<'a, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        format_unit(self.unit, self.style, Notation::Decimal, f)
+    }
+}
+
+impl<'a, U: UnitDisplay> fmt::LowerExp for Styled<'a, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        format_unit(self.unit, self.style, Notation::LowerExp, f)
+    }
+}
+
+impl<'a, U: UnitDisplay> fmt::UpperExp for Styled<'a, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        format_unit(self.unit, self.style, Notation::UpperExp, f)
+    }
+}
+
+fn format_number(value: f64, notation: Notation, f: &fmt::Formatter) -> String {
+    let number = match (notation, f.precision()) {
+        (Notation::Decimal, Some(precision)) => format!("{:.*}", precision, value),
+        (Notation::Decimal, None) => format!("{}", value),
+        (Notation::LowerExp, Some(precision)) => format!("{:.*e}", precision, value),
+        (Notation::LowerExp, None) => format!("{:e}", value),
+        (Notation::UpperExp, Some(precision)) => format!("{:.*E}", precision, value),
+        (Notation::UpperExp, None) => format!("{:E}", value),
+    };
+
+    if f.sign_plus() && !number.starts_with('-') {
+        format!("+{}", number)
+    } else {
+        number
+    }
+}
+
+// Like Formatter::pad, but without truncating the string to the precision.
+// Values with units are right aligned by default, like numbers.
+fn pad(text: &str, f: &mut fmt::Formatter) -> fmt::Result {
+    let length = text.chars().count();
+
+    match f.width() {
+        Some(width) if width > length => {
+            let padding = width - length;
+            let (before, after) = match f.align() {
+                Some(fmt::Alignment::Left) => (0, padding),
+                Some(fmt::Alignment::Center) => (padding / 2, padding - (padding / 2)),
+                _ => (padding, 0),
+            };
+            let fill = f.fill();
+
+            for _ in 0..before {
+                write!(f, "{}", fill)?;
+            }
+            f.write_str(text)?;
+            for _ in 0..after {
+                write!(f, "{}", fill)?;
+            }
+            Ok(())
+        }
+        _ => f.write_str(text),
+    }
+}
+
+/// Writes the value and the symbol of the unit, used by the macros
+#[doc(hidden)]
+pub fn format_unit<U: UnitDisplay>(unit: &U, style: DisplayStyle, notation: Notation, f: &mut fmt::Formatter) -> fmt::Result {
+    let number = format_number(unit.display_value(), notation, f);
+    let symbol = U::display_symbol(style);
+
+    let text = match (style, symbol.is_empty()) {
+        (DisplayStyle::Latex, true) => format!("\\num{{{}}}", number),
+        (DisplayStyle::Latex, false) => format!("\\SI{{{}}}{{{}}}", number, symbol),
+        (_, true) => number,
+        (_, false) => format!("{} {}", number, symbol),
+    };
+
+    pad(&text, f)
+}
+
+struct Symbol {
+    ascii: &'static str,
+    unicode: &'static str,
+    latex: &'static str,
+}
+
+impl Symbol {
+    fn get(&self, style: DisplayStyle) -> &'static str {
+        match style {
+            DisplayStyle::Ascii => self.ascii,
+            DisplayStyle::Unicode => self.unicode,
+            DisplayStyle::Latex => self.latex,
+        }
+    }
+}
+
+// In the order of the exponents: length, mass, time, temperature, amount, current, luminosity
+const BASE_SYMBOLS: [Symbol; BASE_QUANTITIES] = [
+    Symbol { ascii: "m", unicode: "m", latex: "\\metre" },
+    Symbol { ascii: "kg", unicode: "kg", latex: "\\kilogram" },
+    Symbol { ascii: "s", unicode: "s", latex: "\\second" },
+    Symbol { ascii: "K", unicode: "K", latex: "\\kelvin" },
+    Symbol { ascii: "mol", unicode: "mol", latex: "\\mole" },
+    Symbol { ascii: "A", unicode: "A", latex: "\\ampere" },
+    Symbol { ascii: "cd", unicode: "cd", latex: "\\candela" },
+];
+
+// Derived units with a special name are used if the dimension matches exactly
+const DERIVED_SYMBOLS: [([i32; BASE_QUANTITIES], Symbol); 12] = [
+    ([1, 1, -2, 0, 0, 0, 0], Symbol { ascii: "N", unicode: "N", latex: "\\newton" }),
+    ([-1, 1, -2, 0, 0, 0, 0], Symbol { ascii: "Pa", unicode: "Pa", latex: "\\pascal" }),
+    ([2, 1, -2, 0, 0, 0, 0], Symbol { ascii: "J", unicode: "J", latex: "\\joule" }),
+    ([2, 1, -3, 0, 0, 0, 0], Symbol { ascii: "W", unicode: "W", latex: "\\watt" }),
+    ([0, 0, 1, 0, 0, 1, 0], Symbol { ascii: "C", unicode: "C", latex: "\\coulomb" }),
+    ([2, 1, -3, 0, 0, -1, 0], Symbol { ascii: "V", unicode: "V", latex: "\\volt" }),
+    ([2, 1, -3, 0, 0, -2, 0], Symbol { ascii: "Ohm", unicode: "Ω", latex: "\\ohm" }),
+    ([-2, -1, 3, 0, 0, 2, 0], Symbol { ascii: "S", unicode: "S", latex: "\\siemens" }),
+    ([-2, -1, 4, 0, 0, 2, 0], Symbol { ascii: "F", unicode: "F", latex: "\\farad" }),
+    ([2, 1, -2, 0, 0, -2, 0], Symbol { ascii: "H", unicode: "H", latex: "\\henry" }),
+    ([2, 1, -2, 0, 0, -1, 0], Symbol { ascii: "Wb", unicode: "Wb", latex: "\\weber" }),
+    ([0, 1, -2, 0, 0, -1, 0], Symbol { ascii: "T", unicode: "T", latex: "\\tesla" }),
+];
+
+fn superscript(exponent: i32) -> String {
+    exponent.to_string().chars().map(|c| match c {
+        '-' => '⁻',
+        '0' => '⁰', '1' => '¹', '2' => '²', '3' => '³', '4' => '⁴',
+        '5' => '⁵', '6' => '⁶', '7' => '⁷', '8' => '⁸', _ => '⁹',
+    }).collect()
+}
+
+fn ascii_power(symbol: &str, exponent: i32) -> String {
+    if exponent == 1 {
+        symbol.to_string()
+    } else {
+        format!("{}^{}", symbol, exponent)
+    }
+}
+
+fn latex_power(symbol: &str, exponent: i32) -> String {
+    match exponent {
+        1 => symbol.to_string(),
+        2 => format!("{}\\squared", symbol),
+        3 => format!("{}\\cubed", symbol),
+        _ => format!("{}\\tothe{{{}}}", symbol, exponent),
+    }
+}
+
+/// Builds the symbol of a unit from the exponents of its dimension
+pub fn dimension_symbol(exponents: [i32; BASE_QUANTITIES], style: DisplayStyle) -> String {
+    if let Some((_, symbol)) = DERIVED_SYMBOLS.iter().find(|&&(e, _)| e == exponents) {
+        return symbol.get(style).to_string();
+    }
+
+    let factors = BASE_SYMBOLS.iter().zip(exponents.iter())
+        .filter(|&(_, &exponent)| exponent != 0)
+        .map(|(symbol, &exponent)| (symbol.get(style), exponent));
+
+    match style {
+        DisplayStyle::Ascii => {
+            let numerator: Vec<String> = factors.clone().filter(|&(_, e)| e > 0)
+                .map(|(s, e)| ascii_power(s, e)).collect();
+            let denominator: Vec<String> = factors.filter(|&(_, e)| e < 0)
+                .map(|(s, e)| ascii_power(s, -e)).collect();
+
+            let numerator = if numerator.is_empty() && !denominator.is_empty() {
+                "1".to_string()
+            } else {
+                numerator.join("*")
+            };
+
+            match denominator.len() {
+                0 => numerator,
+                1 => format!("{}/{}", numerator, denominator[0]),
+                _ => format!("{}/({})", numerator, denominator.join("*")),
+            }
+        }
+        DisplayStyle::Unicode => {
+            factors.map(|(s, e)| if e == 1 { s.to_string() } else { format!("{}{}", s, superscript(e)) })
+                .collect::<Vec<String>>().join("·")
+        }
+        DisplayStyle::Latex => {
+            let numerator: String = factors.clone().filter(|&(_, e)| e > 0)
+                .map(|(s, e)| latex_power(s, e)).collect();
+            let denominator: String = factors.filter(|&(_, e)| e < 0)
+                .map(|(s, e)| format!("\\per{}", latex_power(s, -e))).collect();
+            numerator + &denominator
+        }
+    }
+}
+
+impl<D: Dimension> UnitDisplay for Quantity<D> {
+    fn display_value(&self) -> f64 {
+        self.0
+    }
+
+    fn display_symbol(style: DisplayStyle) -> String {
+        dimension_symbol(D::EXPONENTS, style)
+    }
+}
+
+impl<D: Dimension> fmt::Display for Quantity<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        format_unit(self, DisplayStyle::Unicode, Notation::Decimal, f)
+    }
+}
+
+impl<D: Dimension> fmt::LowerExp for Quantity<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        format_unit(self, DisplayStyle::Unicode, Notation::LowerExp, f)
+    }
+}
+
+impl<D: Dimension> fmt::UpperExp for Quantity<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        format_unit(self, DisplayStyle::Unicode, Notation::UpperExp, f)
+    }
+}
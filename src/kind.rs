@@ -0,0 +1,48 @@
+//! simple_units: a simple unit system for Rust
+//!
+//! Written by Willi Kappler, Version 0.1 (2017.02.22)
+//!
+//! Repository: https://github.com/willi-kappler/simple_units
+//!
+//! License: MIT
+//!
+//! Kinds of quantities: units with the same dimension that describe different things,
+//! like an energy (Joule) and a torque (NewtonMeter), or a frequency (Hertz) and an
+//! activity (Becquerel). Every kind is a separate unit created with *init_kind*,
+//! conversions between kinds are always explicit:
+//!
+//! ```
+//! # extern crate simple_units;
+//! # use simple_units::si_units::*;
+//! # use simple_units::kind::Kind;
+//! # fn main() {
+//! let torque: NewtonMeter = (Newton(2.0) * Meter(3.0)).tag();
+//! let energy: Joule = Newton(2.0) * Meter(3.0);
+//! assert_eq!(torque.untag(), energy);
+//!
+//! // A frequency is not an activity, the conversion goes visibly through the base unit
+//! let activity = Becquerel::tag(Hertz(50.0).untag());
+//! # }
+//! ```
+//!
+//! Different kinds can not be mixed:
+//!
+//! ```compile_fail
+//! # extern crate simple_units;
+//! # use simple_units::si_units::*;
+//! # fn main() {
+//! let sum = Hertz(50.0) + Becquerel(50.0);
+//! # }
+//! ```
+
+/// A unit that is a different kind of quantity than its base unit
+pub trait Kind: Sized {
+    /// The unit with the same dimension but without kind
+    type Base;
+
+    /// Converts a value of the base unit into this kind
+    fn tag(base: Self::Base) -> Self;
+
+    /// Converts this kind back into the base unit
+    fn untag(self) -> Self::Base;
+}
@@ -22,6 +22,23 @@
 #![allow(non_upper_case_globals)]
 #![allow(dead_code)]
 
+extern crate typenum;
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub extern crate serde;
+
 #[macro_use] pub mod macros;
+pub mod dimension;
+pub mod quantity;
+pub mod kind;
+pub mod prefix;
 pub mod si_units;
 pub mod conversion;
+pub mod constants;
+pub mod parse;
+pub mod display;
+pub mod approx;
+pub mod order;
+pub mod registry;
+#[cfg(feature = "serde")]
+pub mod serialization;
@@ -13,28 +13,63 @@
 ///
 /// ```
 /// # #[macro_use] extern crate simple_units;
-/// # use std::ops::Add;
-/// # use std::ops::Sub;
-/// # use std::ops::Mul;
-/// # use std::ops::Div;
-/// # use std::cmp::PartialEq;
 /// # fn main() {
 /// init_unit!(Meter);
+/// init_unit!(Foot, "ft");
+/// init_unit!(Micrometer, "um", "µm", "\\micro\\metre");
+///
+/// assert_eq!(format!("{}", Foot(2.5)), "2.5 ft");
+/// assert_eq!(format!("{}", Micrometer(2.5)), "2.5 µm");
 /// # }
 /// ```
+///
+/// The optional symbols are used by *Display* and given either once for all styles
+/// or for the ASCII, Unicode and LaTeX (siunitx) style. Without a symbol the name is used.
 #[macro_export] macro_rules! init_unit {
     ($unit:ident) => {
+        $crate::init_unit!($unit, stringify!($unit));
+    };
+
+    ($unit:ident, $symbol:expr) => {
+        $crate::init_unit!($unit, $symbol, $symbol, $symbol);
+    };
+
+    ($unit:ident, $ascii:expr, $unicode:expr, $latex:expr) => {
         #[derive(Debug,Clone,Copy)]
         pub struct $unit(pub f64);
 
-        impl PartialEq for $unit {
+        $crate::unit_display!($unit, $ascii, $unicode, $latex);
+        $crate::unit_impls!($unit);
+    }
+}
+
+/// Helper macro for *init_unit* and the derive macro: everything of a unit besides
+/// the struct and the display (arithmetic, comparison, methods and serialization)
+#[doc(hidden)]
+#[macro_export] macro_rules! unit_impls {
+    ($unit:ident) => {
+        $crate::unit_serde!($unit, Relative);
+        $crate::unit_methods!($unit);
+        $crate::unit_ops!($unit);
+
+        impl $crate::conversion::Convert for $unit {}
+        impl $crate::approx::ApproxEq for $unit {}
+
+        impl ::std::cmp::PartialEq for $unit {
             fn eq(self: &$unit, &$unit(rhs): &$unit) -> bool {
                 let $unit(lhs) = *self;
                 lhs == rhs
             }
         }
 
-        impl Add for $unit {
+        impl ::std::cmp::PartialOrd for $unit {
+            fn partial_cmp(self: &$unit, &$unit(rhs): &$unit) -> ::std::option::Option<::std::cmp::Ordering> {
+                let $unit(lhs) = *self;
+                lhs.partial_cmp(&rhs)
+            }
+        }
+
+        impl ::std::ops::Add for $unit {
             type Output = $unit;
 
             fn add(self: $unit, $unit(rhs): $unit) -> $unit {
@@ -43,7 +78,7 @@
             }
         }
 
-        impl Sub for $unit {
+        impl ::std::ops::Sub for $unit {
             type Output = $unit;
 
             fn sub(self: $unit, $unit(rhs): $unit) -> $unit {
@@ -52,7 +87,7 @@
             }
         }
 
-        impl Mul<f64> for $unit {
+        impl ::std::ops::Mul<f64> for $unit {
             type Output = $unit;
 
             fn mul(self: $unit, rhs: f64) -> $unit {
@@ -61,7 +96,7 @@
             }
         }
 
-        impl Mul<$unit> for f64 {
+        impl ::std::ops::Mul<$unit> for f64 {
             type Output = $unit;
 
             fn mul(self: f64, $unit(rhs): $unit) -> $unit {
@@ -69,7 +104,7 @@
             }
         }
 
-        impl Div<f64> for $unit {
+        impl ::std::ops::Div<f64> for $unit {
             type Output = $unit;
 
             fn div(self: $unit, rhs: f64) -> $unit {
@@ -78,7 +113,7 @@
             }
         }
 
-        impl Div<$unit> for $unit {
+        impl ::std::ops::Div<$unit> for $unit {
             type Output = f64;
 
             fn div(self: $unit, $unit(rhs): $unit) -> f64 {
@@ -89,17 +124,229 @@
     }
 }
 
+/// Helper macro for *init_unit*: implements *Display*, *LowerExp*, *UpperExp*
+/// and *UnitDisplay* with the given symbols
+#[doc(hidden)]
+#[macro_export] macro_rules! unit_display {
+    ($unit:ident, $ascii:expr, $unicode:expr, $latex:expr) => {
+        impl $crate::display::UnitDisplay for $unit {
+            fn display_value(&self) -> f64 {
+                self.0
+            }
+
+            fn display_symbol(style: $crate::display::DisplayStyle) -> ::std::string::String {
+                match style {
+                    $crate::display::DisplayStyle::Ascii => $ascii.to_string(),
+                    $crate::display::DisplayStyle::Unicode => $unicode.to_string(),
+                    $crate::display::DisplayStyle::Latex => $latex.to_string(),
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $unit {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                $crate::display::format_unit(self, $crate::display::DisplayStyle::Unicode,
+                    $crate::display::Notation::Decimal, f)
+            }
+        }
+
+        impl ::std::fmt::LowerExp for $unit {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                $crate::display::format_unit(self, $crate::display::DisplayStyle::Unicode,
+                    $crate::display::Notation::LowerExp, f)
+            }
+        }
+
+        impl ::std::fmt::UpperExp for $unit {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                $crate::display::format_unit(self, $crate::display::DisplayStyle::Unicode,
+                    $crate::display::Notation::UpperExp, f)
+            }
+        }
+    }
+}
+
+/// Helper macro for *init_unit*: implements *Serialize* and *Deserialize*
+/// if the feature "serde" is enabled, see module *serialization*
+#[doc(hidden)]
+#[cfg(feature = "serde")]
+#[macro_export] macro_rules! unit_serde {
+    ($unit:ident, $kind:ident) => {
+        impl $crate::serde::Serialize for $unit {
+            fn serialize<S: $crate::serde::Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+                serializer.serialize_f64(self.0)
+            }
+        }
+
+        impl<'de> $crate::serde::Deserialize<'de> for $unit {
+            fn deserialize<D: $crate::serde::Deserializer<'de>>(deserializer: D) -> ::std::result::Result<$unit, D::Error> {
+                let symbol = <$unit as $crate::display::UnitDisplay>::display_symbol(
+                    $crate::display::DisplayStyle::Ascii);
+                $crate::serialization::deserialize_value(deserializer, &symbol,
+                    $crate::serialization::Scale::$kind).map($unit)
+            }
+        }
+    }
+}
+
+/// Helper macro for *init_unit*: the feature "serde" is not enabled
+#[doc(hidden)]
+#[cfg(not(feature = "serde"))]
+#[macro_export] macro_rules! unit_serde {
+    ($unit:ident, $kind:ident) => {}
+}
+
+/// Helper macro for *init_unit*: the operators besides +, -, * and /:
+/// negation, assignment operators, remainder and the sum of an iterator
+#[doc(hidden)]
+#[macro_export] macro_rules! unit_ops {
+    ($unit:ident) => {
+        impl ::std::ops::Neg for $unit {
+            type Output = $unit;
+
+            fn neg(self) -> $unit {
+                $unit(-self.0)
+            }
+        }
+
+        impl ::std::ops::AddAssign for $unit {
+            fn add_assign(&mut self, rhs: $unit) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl ::std::ops::SubAssign for $unit {
+            fn sub_assign(&mut self, rhs: $unit) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl ::std::ops::MulAssign<f64> for $unit {
+            fn mul_assign(&mut self, rhs: f64) {
+                self.0 *= rhs;
+            }
+        }
+
+        impl ::std::ops::DivAssign<f64> for $unit {
+            fn div_assign(&mut self, rhs: f64) {
+                self.0 /= rhs;
+            }
+        }
+
+        impl ::std::ops::Rem for $unit {
+            type Output = $unit;
+
+            fn rem(self, rhs: $unit) -> $unit {
+                $unit(self.0 % rhs.0)
+            }
+        }
+
+        impl ::std::iter::Sum for $unit {
+            fn sum<I: ::std::iter::Iterator<Item = $unit>>(iter: I) -> $unit {
+                $unit(iter.fold(0.0, |sum, value| sum + value.0))
+            }
+        }
+
+        impl<'a> ::std::iter::Sum<&'a $unit> for $unit {
+            fn sum<I: ::std::iter::Iterator<Item = &'a $unit>>(iter: I) -> $unit {
+                $unit(iter.fold(0.0, |sum, value| sum + value.0))
+            }
+        }
+    }
+}
+
+/// Helper macro for *init_unit*: the methods of f64 that keep the unit.
+/// An absolute scale (*init_absolute_unit*) has an arbitrary zero, where abs, signum,
+/// hypot, mul_add and atan2 are not meaningful, so they are only implemented for the other units.
+#[doc(hidden)]
+#[macro_export] macro_rules! unit_methods {
+    ($unit:ident) => {
+        $crate::unit_methods!($unit, absolute);
+
+        impl $unit {
+            /// Absolute value
+            pub fn abs(self) -> $unit {
+                $unit(self.0.abs())
+            }
+
+            /// Sign of the value: 1.0, -1.0 or NaN
+            pub fn signum(self) -> f64 {
+                self.0.signum()
+            }
+
+            /// Length of the hypotenuse: sqrt(self^2 + other^2)
+            pub fn hypot(self, other: $unit) -> $unit {
+                $unit(self.0.hypot(other.0))
+            }
+
+            /// Fused multiply add: self * factor + offset
+            pub fn mul_add(self, factor: f64, offset: $unit) -> $unit {
+                $unit(self.0.mul_add(factor, offset.0))
+            }
+
+            /// Angle of the point (other, self), like f64::atan2
+            pub fn atan2(self, other: $unit) -> $crate::si_units::Radian {
+                $crate::si_units::Radian(self.0.atan2(other.0))
+            }
+        }
+    };
+
+    ($unit:ident, absolute) => {
+        impl $unit {
+            /// Minimum of two values, NaN is ignored
+            pub fn min(self, other: $unit) -> $unit {
+                $unit(self.0.min(other.0))
+            }
+
+            /// Maximum of two values, NaN is ignored
+            pub fn max(self, other: $unit) -> $unit {
+                $unit(self.0.max(other.0))
+            }
+
+            /// Restricts the value to the interval [min, max]
+            pub fn clamp(self, min: $unit, max: $unit) -> $unit {
+                $unit(self.0.clamp(min.0, max.0))
+            }
+
+            /// Largest integer value less than or equal to the value
+            pub fn floor(self) -> $unit {
+                $unit(self.0.floor())
+            }
+
+            /// Smallest integer value greater than or equal to the value
+            pub fn ceil(self) -> $unit {
+                $unit(self.0.ceil())
+            }
+
+            /// Nearest integer value, half-way cases away from zero
+            pub fn round(self) -> $unit {
+                $unit(self.0.round())
+            }
+
+            /// True if the value is NaN
+            pub fn is_nan(self) -> bool {
+                self.0.is_nan()
+            }
+
+            /// True if the value is neither infinite nor NaN
+            pub fn is_finite(self) -> bool {
+                self.0.is_finite()
+            }
+
+            /// Total ordering like f64::total_cmp, for sorting values that may be NaN
+            pub fn total_cmp(&self, other: &$unit) -> ::std::cmp::Ordering {
+                self.0.total_cmp(&other.0)
+            }
+        }
+    };
+}
+
 /// This macro implements multiplication and division for a given unit
 ///
 /// # Example:
 ///
 /// ```
 /// # #[macro_use] extern crate simple_units;
-/// # use std::ops::Add;
-/// # use std::ops::Sub;
-/// # use std::ops::Mul;
-/// # use std::ops::Div;
-/// # use std::cmp::PartialEq;
 /// # fn main() {
 /// init_unit!(Joule);
 /// init_unit!(Meter);
@@ -113,39 +360,35 @@
     // $unit1 = $unit3 / $unit2
     // $unit2 = $unit3 / $unit1
     ($unit1:ident, $unit2:ident, $unit3:ident) => {
-        impl Mul<$unit2> for $unit1 {
+        impl ::std::ops::Mul<$unit2> for $unit1 {
             type Output = $unit3;
 
-            fn mul(self: $unit1, $unit2(rhs): $unit2) -> $unit3 {
-                let $unit1(lhs) = self;
-                $unit3(lhs * rhs)
+            fn mul(self: $unit1, rhs: $unit2) -> $unit3 {
+                $unit3(self.0 * rhs.0)
             }
         }
 
-        impl Mul<$unit1> for $unit2 {
+        impl ::std::ops::Mul<$unit1> for $unit2 {
             type Output = $unit3;
 
-            fn mul(self: $unit2, $unit1(rhs): $unit1) -> $unit3 {
-                let $unit2(lhs) = self;
-                $unit3(lhs * rhs)
+            fn mul(self: $unit2, rhs: $unit1) -> $unit3 {
+                $unit3(self.0 * rhs.0)
             }
         }
 
-        impl Div<$unit2> for $unit3 {
+        impl ::std::ops::Div<$unit2> for $unit3 {
             type Output = $unit1;
 
-            fn div(self: $unit3, $unit2(rhs): $unit2) -> $unit1 {
-                let $unit3(lhs) = self;
-                $unit1(lhs / rhs)
+            fn div(self: $unit3, rhs: $unit2) -> $unit1 {
+                $unit1(self.0 / rhs.0)
             }
         }
 
-        impl Div<$unit1> for $unit3 {
+        impl ::std::ops::Div<$unit1> for $unit3 {
             type Output = $unit2;
 
-            fn div(self: $unit3, $unit1(rhs): $unit1) -> $unit2 {
-                let $unit3(lhs) = self;
-                $unit2(lhs / rhs)
+            fn div(self: $unit3, rhs: $unit1) -> $unit2 {
+                $unit2(self.0 / rhs.0)
             }
         }
     };
@@ -153,21 +396,19 @@
     // $unit1 * $unit1 = $unit2
     // $unit1 = $unit2 / $unit1
     ($unit1:ident, $unit2:ident) => {
-        impl Mul<$unit1> for $unit1 {
+        impl ::std::ops::Mul<$unit1> for $unit1 {
             type Output = $unit2;
 
-            fn mul(self: $unit1, $unit1(rhs): $unit1) -> $unit2 {
-                let $unit1(lhs) = self;
-                $unit2(lhs * rhs)
+            fn mul(self: $unit1, rhs: $unit1) -> $unit2 {
+                $unit2(self.0 * rhs.0)
             }
         }
 
-        impl Div<$unit1> for $unit2 {
+        impl ::std::ops::Div<$unit1> for $unit2 {
             type Output = $unit1;
 
-            fn div(self: $unit2, $unit1(rhs): $unit1) -> $unit1 {
-                let $unit2(lhs) = self;
-                $unit1(lhs / rhs)
+            fn div(self: $unit2, rhs: $unit1) -> $unit1 {
+                $unit1(self.0 / rhs.0)
             }
         }
     }
@@ -179,11 +420,6 @@
 ///
 /// ```
 /// # #[macro_use] extern crate simple_units;
-/// # use std::ops::Add;
-/// # use std::ops::Sub;
-/// # use std::ops::Mul;
-/// # use std::ops::Div;
-/// # use std::cmp::PartialEq;
 /// # fn main() {
 /// init_unit!(Meter);
 /// init_unit!(PerMeter);
@@ -193,40 +429,38 @@
 #[macro_export] macro_rules! inverse_unit {
     ($unit1:ident, $unit2:ident) => {
         // A * B = 1
-        impl Mul<$unit2> for $unit1 {
+        impl ::std::ops::Mul<$unit2> for $unit1 {
             type Output = f64;
 
-            fn mul(self: $unit1, $unit2(rhs): $unit2) -> f64 {
-                let $unit1(lhs) = self;
-                lhs * rhs
+            fn mul(self: $unit1, rhs: $unit2) -> f64 {
+                self.0 * rhs.0
             }
         }
 
         // B * A = 1
-        impl Mul<$unit1> for $unit2 {
+        impl ::std::ops::Mul<$unit1> for $unit2 {
             type Output = f64;
 
-            fn mul(self: $unit2, $unit1(rhs): $unit1) -> f64 {
-                let $unit2(lhs) = self;
-                lhs * rhs
+            fn mul(self: $unit2, rhs: $unit1) -> f64 {
+                self.0 * rhs.0
             }
         }
 
         // 1 / A = B
-        impl Div<$unit1> for f64 {
+        impl ::std::ops::Div<$unit1> for f64 {
             type Output = $unit2;
 
-            fn div(self: f64, $unit1(rhs): $unit1) -> $unit2 {
-                $unit2(self / rhs)
+            fn div(self: f64, rhs: $unit1) -> $unit2 {
+                $unit2(self / rhs.0)
             }
         }
 
         // 1 / B = A
-        impl Div<$unit2> for f64 {
+        impl ::std::ops::Div<$unit2> for f64 {
             type Output = $unit1;
 
-            fn div(self: f64, $unit2(rhs): $unit2) -> $unit1 {
-                $unit1(self / rhs)
+            fn div(self: f64, rhs: $unit2) -> $unit1 {
+                $unit1(self / rhs.0)
             }
         }
     }
@@ -238,20 +472,15 @@
 ///
 /// ```
 /// # #[macro_use] extern crate simple_units;
-/// # use std::ops::Add;
-/// # use std::ops::Sub;
-/// # use std::ops::Mul;
-/// # use std::ops::Div;
-/// # use std::cmp::PartialEq;
 /// # fn main() {
 /// init_unit_and_inverse!(Meter, PerMeter);
 /// # }
 /// ```
 #[macro_export] macro_rules! init_unit_and_inverse {
     ($unit1:ident, $per_unit1:ident) => {
-        init_unit!($unit1);
-        init_unit!($per_unit1);
-        inverse_unit!($unit1, $per_unit1);
+        $crate::init_unit!($unit1);
+        $crate::init_unit!($per_unit1);
+        $crate::inverse_unit!($unit1, $per_unit1);
     }
 }
 
@@ -261,11 +490,6 @@
 ///
 /// ```
 /// # #[macro_use] extern crate simple_units;
-/// # use std::ops::Add;
-/// # use std::ops::Sub;
-/// # use std::ops::Mul;
-/// # use std::ops::Div;
-/// # use std::cmp::PartialEq;
 /// # fn main() {
 /// init_unit!(Second);
 /// init_unit!(Second2);
@@ -278,20 +502,20 @@
 /// ```
 #[macro_export] macro_rules! power3_unit {
     ($unit1:ident, $unit2:ident, $unit3:ident, $per_unit1:ident, $per_unit2:ident, $per_unit3:ident) => {
-        mul_div_unit!($unit1, $unit2);
-        mul_div_unit!($unit1, $unit2, $unit3);
+        $crate::mul_div_unit!($unit1, $unit2);
+        $crate::mul_div_unit!($unit1, $unit2, $unit3);
 
-        mul_div_unit!($per_unit1, $per_unit2);
-        mul_div_unit!($per_unit1, $per_unit2, $per_unit3);
+        $crate::mul_div_unit!($per_unit1, $per_unit2);
+        $crate::mul_div_unit!($per_unit1, $per_unit2, $per_unit3);
 
-        mul_div_unit!($unit1, $per_unit2, $per_unit1);
-        mul_div_unit!($unit1, $per_unit3, $per_unit2);
+        $crate::mul_div_unit!($unit1, $per_unit2, $per_unit1);
+        $crate::mul_div_unit!($unit1, $per_unit3, $per_unit2);
 
-        mul_div_unit!($unit2, $per_unit1, $unit1);
-        mul_div_unit!($unit2, $per_unit3, $per_unit1);
+        $crate::mul_div_unit!($unit2, $per_unit1, $unit1);
+        $crate::mul_div_unit!($unit2, $per_unit3, $per_unit1);
 
-        mul_div_unit!($unit3, $per_unit1, $unit2);
-        mul_div_unit!($unit3, $per_unit2, $unit1);
+        $crate::mul_div_unit!($unit3, $per_unit1, $unit2);
+        $crate::mul_div_unit!($unit3, $per_unit2, $unit1);
     }
 }
 
@@ -301,11 +525,6 @@
 ///
 /// ```
 /// # #[macro_use] extern crate simple_units;
-/// # use std::ops::Add;
-/// # use std::ops::Sub;
-/// # use std::ops::Mul;
-/// # use std::ops::Div;
-/// # use std::cmp::PartialEq;
 /// # fn main() {
 /// init_unit!(Meter);
 /// init_unit!(PerMeter);
@@ -320,76 +539,623 @@
 /// ```
 #[macro_export] macro_rules! combine_unit {
     ($unit1:ident, $per_unit1:ident, $unit2:ident, $per_unit2:ident, $unit1_unit2:ident, $unit1_per_unit2:ident, $unit2_per_unit1:ident, $per_unit1_unit2:ident) => {
-        mul_div_unit!($unit1, $unit2, $unit1_unit2);
-        mul_div_unit!($unit1, $per_unit2, $unit1_per_unit2);
+        $crate::mul_div_unit!($unit1, $unit2, $unit1_unit2);
+        $crate::mul_div_unit!($unit1, $per_unit2, $unit1_per_unit2);
+
+        $crate::mul_div_unit!($unit1_per_unit2, $unit2, $unit1);
+        $crate::mul_div_unit!($unit1_per_unit2, $per_unit1, $per_unit2);
+
+        $crate::mul_div_unit!($unit2_per_unit1, $unit1, $unit2);
+        $crate::mul_div_unit!($unit2_per_unit1, $per_unit2, $per_unit1);
+
+        $crate::mul_div_unit!($unit2, $per_unit1, $unit2_per_unit1);
+        $crate::mul_div_unit!($per_unit1, $per_unit2, $per_unit1_unit2);
+
+        $crate::mul_div_unit!($unit1_unit2, $per_unit1, $unit2);
+        $crate::mul_div_unit!($unit1_unit2, $per_unit2, $unit1);
+
+        $crate::mul_div_unit!($per_unit1_unit2, $unit1, $per_unit2);
+        $crate::mul_div_unit!($per_unit1_unit2, $unit2, $per_unit1);
+    }
+}
+
+/// This macro initializes a new absolute unit (a point on a scale, like a temperature)
+/// together with the unit of the difference between two points.
+/// The difference of two absolute values gives a difference, a difference can be added
+/// to or subtracted from an absolute value, but two absolute values can not be added.
+///
+/// # Example:
+///
+/// ```
+/// # #[macro_use] extern crate simple_units;
+/// # fn main() {
+/// init_unit!(DegCDelta);
+/// init_absolute_unit!(DegC, DegCDelta);
+///
+/// assert_eq!(DegC(30.0) - DegC(20.0), DegCDelta(10.0));
+/// assert_eq!(DegC(20.0) + DegCDelta(5.0), DegC(25.0));
+/// # }
+/// ```
+///
+/// Adding two absolute values does not compile:
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate simple_units;
+/// # fn main() {
+/// init_unit!(DegCDelta);
+/// init_absolute_unit!(DegC, DegCDelta);
+///
+/// let sum = DegC(30.0) + DegC(20.0);
+/// # }
+/// ```
+#[macro_export] macro_rules! init_absolute_unit {
+    ($unit:ident, $delta:ident) => {
+        $crate::init_absolute_unit!($unit, $delta, stringify!($unit), stringify!($unit), stringify!($unit));
+    };
+
+    ($unit:ident, $delta:ident, $ascii:expr, $unicode:expr, $latex:expr) => {
+        #[derive(Debug,Clone,Copy)]
+        pub struct $unit(pub f64);
+
+        $crate::unit_display!($unit, $ascii, $unicode, $latex);
+        $crate::unit_serde!($unit, Absolute);
+        $crate::unit_methods!($unit, absolute);
+
+        impl $crate::conversion::Convert for $unit {}
+        impl $crate::approx::ApproxEq for $unit {}
+
+        impl ::std::cmp::PartialEq for $unit {
+            fn eq(self: &$unit, rhs: &$unit) -> bool {
+                self.0 == rhs.0
+            }
+        }
+
+        impl ::std::cmp::PartialOrd for $unit {
+            fn partial_cmp(self: &$unit, rhs: &$unit) -> ::std::option::Option<::std::cmp::Ordering> {
+                self.0.partial_cmp(&rhs.0)
+            }
+        }
+
+        // A - A = delta
+        impl ::std::ops::Sub for $unit {
+            type Output = $delta;
 
-        mul_div_unit!($unit1_per_unit2, $unit2, $unit1);
-        mul_div_unit!($unit1_per_unit2, $per_unit1, $per_unit2);
+            fn sub(self: $unit, rhs: $unit) -> $delta {
+                $delta(self.0 - rhs.0)
+            }
+        }
 
-        mul_div_unit!($unit2_per_unit1, $unit1, $unit2);
-        mul_div_unit!($unit2_per_unit1, $per_unit2, $per_unit1);
+        // A + delta = A
+        impl ::std::ops::Add<$delta> for $unit {
+            type Output = $unit;
 
-        mul_div_unit!($unit2, $per_unit1, $unit2_per_unit1);
-        mul_div_unit!($per_unit1, $per_unit2, $per_unit1_unit2);
+            fn add(self: $unit, rhs: $delta) -> $unit {
+                $unit(self.0 + rhs.0)
+            }
+        }
 
-        mul_div_unit!($unit1_unit2, $per_unit1, $unit2);
-        mul_div_unit!($unit1_unit2, $per_unit2, $unit1);
+        // delta + A = A
+        impl ::std::ops::Add<$unit> for $delta {
+            type Output = $unit;
+
+            fn add(self: $delta, rhs: $unit) -> $unit {
+                $unit(self.0 + rhs.0)
+            }
+        }
+
+        // A - delta = A
+        impl ::std::ops::Sub<$delta> for $unit {
+            type Output = $unit;
+
+            fn sub(self: $unit, rhs: $delta) -> $unit {
+                $unit(self.0 - rhs.0)
+            }
+        }
+
+        // A += delta
+        impl ::std::ops::AddAssign<$delta> for $unit {
+            fn add_assign(&mut self, rhs: $delta) {
+                self.0 += rhs.0;
+            }
+        }
 
-        mul_div_unit!($per_unit1_unit2, $unit1, $per_unit2);
-        mul_div_unit!($per_unit1_unit2, $unit2, $per_unit1);
+        // A -= delta
+        impl ::std::ops::SubAssign<$delta> for $unit {
+            fn sub_assign(&mut self, rhs: $delta) {
+                self.0 -= rhs.0;
+            }
+        }
     }
 }
 
 /// This macro implements *From* for the given units.
-/// It either uses a factor or a closure to calculate the conversion
+/// It either uses the size of the unit or a closure to calculate the conversion.
+/// The size is the value of one $unit expressed in $base,
+/// for example one minute is 60 seconds:
 ///
 /// # Example:
 ///
 /// ```
 /// # #[macro_use] extern crate simple_units;
-/// # use std::ops::Add;
-/// # use std::ops::Sub;
-/// # use std::ops::Mul;
-/// # use std::ops::Div;
-/// # use std::cmp::PartialEq;
 /// # fn main() {
-/// const factor: f64 = 60.0;
+/// const MINUTE_IN_SECONDS: f64 = 60.0;
 /// init_unit!(Second);
 /// init_unit!(Minute);
-/// convert_unit!(Second, Minute, factor);
+/// convert_unit!(Second, Minute, size = MINUTE_IN_SECONDS);
+///
+/// assert_eq!(Minute::from(Second(120.0)), Minute(2.0));
+/// assert_eq!(Second::from(Minute(2.0)), Second(120.0));
 /// # }
 /// ```
 #[macro_export] macro_rules! convert_unit {
     // Convert from $i1 to $i2 using closure $e1
     // Convert from $i2 to $i1 using closure $e2
     ($i1:ident, $i2:ident, $e1:expr, $e2:expr) => {
-        impl From<$i1> for $i2 {
-            fn from($i1(value): $i1) -> Self {
-                $i2($e1(value))
+        impl ::std::convert::From<$i1> for $i2 {
+            fn from(value: $i1) -> Self {
+                $i2($e1(value.0))
             }
         }
 
-        impl From<$i2> for $i1 {
-            fn from($i2(value): $i2) -> Self {
-                $i1($e2(value))
+        impl ::std::convert::From<$i2> for $i1 {
+            fn from(value: $i2) -> Self {
+                $i1($e2(value.0))
             }
         }
     };
 
-    // Convert from $i1 to $i2 using factor $i3
-    // $i2 = $i1 * $i3
-    ($i1:ident, $i2:ident, $i3:ident) => {
-        impl From<$i1> for $i2 {
-            fn from($i1(value): $i1) -> Self {
-                $i2(value * $i3)
+    // One $unit is $size $base:
+    // $base = $unit * $size
+    // $unit = $base / $size
+    ($base:ident, $unit:ident, size = $size:expr) => {
+        impl ::std::convert::From<$base> for $unit {
+            fn from(value: $base) -> Self {
+                $unit(value.0 / $size)
             }
         }
 
-        impl From<$i2> for $i1 {
-            fn from($i2(value): $i2) -> Self {
-                $i1(value / $i3)
+        impl ::std::convert::From<$unit> for $base {
+            fn from(value: $unit) -> Self {
+                $base(value.0 * $size)
             }
         }
     };
 
 }
+
+/// This macro implements *From* between every pair of the given units
+/// by converting through $base. Every unit must already be convertible to
+/// and from $base, for example with *convert_unit* or *prefix_unit*:
+///
+/// # Example:
+///
+/// ```
+/// # #[macro_use] extern crate simple_units;
+/// # fn main() {
+/// init_unit!(Second);
+/// init_unit!(Minute);
+/// init_unit!(Hour);
+/// init_unit!(Day);
+/// convert_unit!(Second, Minute, size = 60.0);
+/// convert_unit!(Second, Hour, size = 3600.0);
+/// convert_unit!(Second, Day, size = 86400.0);
+/// convert_via_base!(Second, Minute, Hour, Day);
+///
+/// assert_eq!(Day::from(Hour(48.0)), Day(2.0));
+/// assert_eq!(Minute::from(Hour(2.0)), Minute(120.0));
+/// # }
+/// ```
+#[macro_export] macro_rules! convert_via_base {
+    ($base:ident, $unit:ident, $($rest:ident),+) => {
+        $(
+            impl ::std::convert::From<$unit> for $rest {
+                fn from(value: $unit) -> Self {
+                    <$rest as ::std::convert::From<$base>>::from(<$base as ::std::convert::From<$unit>>::from(value))
+                }
+            }
+
+            impl ::std::convert::From<$rest> for $unit {
+                fn from(value: $rest) -> Self {
+                    <$unit as ::std::convert::From<$base>>::from(<$base as ::std::convert::From<$rest>>::from(value))
+                }
+            }
+
+            $crate::compare_unit!($unit, $rest);
+        )+
+
+        $crate::compare_unit!($base, $unit);
+        $crate::convert_via_base!($base, $($rest),+);
+    };
+
+    ($base:ident, $unit:ident) => {
+        $crate::compare_unit!($base, $unit);
+    };
+}
+
+/// This macro implements *PartialEq* and *PartialOrd* between two units that can be
+/// converted into each other. Both directions compare in the first unit, so a == b and b == a agree.
+/// *convert_via_base* uses it for every pair of units, so all units with the same dimension
+/// can be compared:
+///
+/// # Example:
+///
+/// ```
+/// # #[macro_use] extern crate simple_units;
+/// # fn main() {
+/// init_unit!(Second);
+/// init_unit!(Minute);
+/// convert_unit!(Second, Minute, size = 60.0);
+/// compare_unit!(Second, Minute);
+///
+/// assert!(Second(90.0) > Minute(1.0));
+/// assert!(Minute(1.0) == Second(60.0));
+/// # }
+/// ```
+#[macro_export] macro_rules! compare_unit {
+    ($i1:ty, $i2:ty) => {
+        impl ::std::cmp::PartialEq<$i2> for $i1 {
+            fn eq(&self, rhs: &$i2) -> bool {
+                self.0 == <$i1 as ::std::convert::From<$i2>>::from(*rhs).0
+            }
+        }
+
+        impl ::std::cmp::PartialEq<$i1> for $i2 {
+            fn eq(&self, rhs: &$i1) -> bool {
+                <$i1 as ::std::convert::From<$i2>>::from(*self).0 == rhs.0
+            }
+        }
+
+        impl ::std::cmp::PartialOrd<$i2> for $i1 {
+            fn partial_cmp(&self, rhs: &$i2) -> ::std::option::Option<::std::cmp::Ordering> {
+                self.0.partial_cmp(&<$i1 as ::std::convert::From<$i2>>::from(*rhs).0)
+            }
+        }
+
+        impl ::std::cmp::PartialOrd<$i1> for $i2 {
+            fn partial_cmp(&self, rhs: &$i1) -> ::std::option::Option<::std::cmp::Ordering> {
+                <$i1 as ::std::convert::From<$i2>>::from(*self).0.partial_cmp(&rhs.0)
+            }
+        }
+    };
+}
+
+/// This macro implements sin, cos and tan for an angle unit.
+/// The unit must be convertible to *Radian*:
+///
+/// # Example:
+///
+/// ```
+/// # #[macro_use] extern crate simple_units;
+/// # use simple_units::si_units::Radian;
+/// # fn main() {
+/// init_unit!(Turn);
+/// convert_unit!(Radian, Turn, size = 2.0 * std::f64::consts::PI);
+/// angle_unit!(Turn);
+///
+/// assert!((Turn(0.25).sin().0 - 1.0).abs() < 1.0e-15);
+/// # }
+/// ```
+///
+/// Angles are separate types, an angle in degrees can not be used as radian:
+///
+/// ```compile_fail
+/// # extern crate simple_units;
+/// # use simple_units::si_units::*;
+/// # use simple_units::conversion::*;
+/// # fn main() {
+/// fn rotate(angle: Radian) -> Radian {
+///     angle + Radian(1.0)
+/// }
+///
+/// rotate(Degree(90.0));
+/// # }
+/// ```
+#[macro_export] macro_rules! angle_unit {
+    ($unit:ident) => {
+        impl $unit {
+            /// Sine of the angle
+            pub fn sin(self) -> $crate::si_units::Unitless {
+                $crate::si_units::Radian::from(self).sin()
+            }
+
+            /// Cosine of the angle
+            pub fn cos(self) -> $crate::si_units::Unitless {
+                $crate::si_units::Radian::from(self).cos()
+            }
+
+            /// Tangent of the angle
+            pub fn tan(self) -> $crate::si_units::Unitless {
+                $crate::si_units::Radian::from(self).tan()
+            }
+        }
+    }
+}
+
+/// This macro initializes a new unit with a prefix (see module *prefix*)
+/// and implements the conversion from and to the unprefixed unit.
+/// Any unit that can be used with *convert_unit* can be prefixed.
+///
+/// # Example:
+///
+/// ```
+/// # #[macro_use] extern crate simple_units;
+/// # use simple_units::prefix::*;
+/// # fn main() {
+/// init_unit!(Second, "s");
+/// prefix_unit!(Second, Millisecond, MILLI, "ms");
+///
+/// assert_eq!(Millisecond::from(Second(2.0)), Millisecond(2000.0));
+/// assert_eq!(format!("{}", Millisecond(2.0)), "2 ms");
+/// # }
+/// ```
+#[macro_export] macro_rules! prefix_unit {
+    ($unit:ident, $prefixed_unit:ident, $prefix:expr) => {
+        $crate::init_unit!($prefixed_unit);
+        $crate::convert_unit!($unit, $prefixed_unit, size = $prefix);
+    };
+
+    ($unit:ident, $prefixed_unit:ident, $prefix:expr, $($symbol:expr),+) => {
+        $crate::init_unit!($prefixed_unit, $($symbol),+);
+        $crate::convert_unit!($unit, $prefixed_unit, size = $prefix);
+    };
+}
+
+/// This macro initializes a unit that has the dimension of $base but describes
+/// a different kind of quantity, for example a torque in newton meter is not an energy.
+/// The two units can only be converted explicitly with the trait *Kind* (see module *kind*)
+/// or with *From*. The optional size is the value of one $unit expressed in $base.
+///
+/// # Example:
+///
+/// ```
+/// # #[macro_use] extern crate simple_units;
+/// # use simple_units::si_units::*;
+/// # use simple_units::kind::Kind;
+/// # fn main() {
+/// init_kind!(Torque, Joule, "N*m", "N·m", "\\newton\\metre");
+/// init_kind!(Barn, Meter2, size = 1.0e-28, "b");
+///
+/// let torque: Torque = (Newton(2.0) * Meter(3.0)).tag();
+/// assert_eq!(torque, Torque(6.0));
+/// assert_eq!(torque.untag(), Joule(6.0));
+/// assert_eq!(Barn::tag(Meter2(2.0e-28)), Barn(2.0));
+/// # }
+/// ```
+#[macro_export] macro_rules! init_kind {
+    ($unit:ident, $base:ident, size = $size:expr, $($symbol:expr),+) => {
+        $crate::init_unit!($unit, $($symbol),+);
+        $crate::convert_unit!($base, $unit, size = $size);
+
+        impl $crate::kind::Kind for $unit {
+            type Base = $base;
+
+            fn tag(base: $base) -> $unit {
+                ::std::convert::From::from(base)
+            }
+
+            fn untag(self) -> $base {
+                ::std::convert::From::from(self)
+            }
+        }
+    };
+
+    ($unit:ident, $base:ident, $($symbol:expr),+) => {
+        $crate::init_kind!($unit, $base, size = 1.0, $($symbol),+);
+    };
+
+    ($unit:ident, $base:ident) => {
+        $crate::init_kind!($unit, $base, size = 1.0, stringify!($unit));
+    };
+}
+
+/// This macro implements *FromStr* for the given unit by parsing the string
+/// into the base unit (which must implement *FromStr*) and converting it with *From*.
+/// See module *parse* for the syntax. For units that share their dimension with
+/// a different kind of unit (a quantity, see module *kind*) one symbol of each accepted kind is given:
+/// parse_unit!(Steradian, Unitless, "sr")
+///
+/// # Example:
+///
+/// ```
+/// # #[macro_use] extern crate simple_units;
+/// # use simple_units::si_units::*;
+/// # fn main() {
+/// init_unit!(Minute);
+/// convert_unit!(Second, Minute, size = 60.0);
+/// parse_unit!(Minute, Second);
+///
+/// assert_eq!("2 h".parse::<Minute>(), Ok(Minute(120.0)));
+/// assert!("2 m".parse::<Minute>().is_err());
+/// # }
+/// ```
+#[macro_export] macro_rules! parse_unit {
+    ($unit:ident, $base:ident) => {
+        impl ::std::str::FromStr for $unit {
+            type Err = $crate::parse::ParseError;
+
+            fn from_str(input: &str) -> ::std::result::Result<$unit, $crate::parse::ParseError> {
+                input.parse::<$base>().map($unit::from)
+            }
+        }
+    };
+
+    // Only units of the kinds of the given symbols are accepted, see *parse_kind_quantity*
+    ($unit:ident, $base:ident, $($symbol:expr),+) => {
+        impl ::std::str::FromStr for $unit {
+            type Err = $crate::parse::ParseError;
+
+            fn from_str(input: &str) -> ::std::result::Result<$unit, $crate::parse::ParseError> {
+                let value: $base = $crate::parse::parse_kind_quantity(input, &[$($symbol),+])?;
+                ::std::result::Result::Ok($unit::from(value))
+            }
+        }
+    };
+}
+
+/// This macro defines a unit as an alias of the generic *Quantity* type.
+/// The dimension is either given as a type or as the seven exponents
+/// (length, mass, time, temperature, amount, current, luminosity) using
+/// the typenum integers re-exported in *dimension* (N6 ... Z0 ... P6).
+/// A constructor function with the same name is also generated,
+/// so the unit can be used like a tuple struct: Meter(1.0)
+///
+/// # Example:
+///
+/// ```
+/// # #[macro_use] extern crate simple_units;
+/// # use simple_units::dimension::Length;
+/// # fn main() {
+/// init_quantity!(Meter, Length);
+/// init_quantity!(Meter2, P2, Z0, Z0, Z0, Z0, Z0, Z0);
+/// init_quantity!(MeterPerSecond, P1, Z0, N1, Z0, Z0, Z0, Z0);
+/// init_quantity!(PerSecond, Z0, Z0, N1, Z0, Z0, Z0, Z0);
+///
+/// let velocity: MeterPerSecond = Meter2(6.0) / Meter(2.0) * PerSecond(1.0 / 1.5);
+/// assert_eq!(velocity, MeterPerSecond(2.0));
+/// # }
+/// ```
+#[macro_export] macro_rules! init_quantity {
+    ($unit:ident, $l:ident, $m:ident, $t:ident, $th:ident, $n:ident, $i:ident, $j:ident) => {
+        $crate::init_quantity!($unit, $crate::dimension::Dim<$crate::dimension::$l, $crate::dimension::$m,
+            $crate::dimension::$t, $crate::dimension::$th, $crate::dimension::$n,
+            $crate::dimension::$i, $crate::dimension::$j>);
+    };
+
+    ($unit:ident, $dim:ty) => {
+        pub type $unit = $crate::quantity::Quantity<$dim>;
+
+        #[allow(non_snake_case)]
+        pub const fn $unit(value: f64) -> $unit {
+            $crate::quantity::Quantity::new(value)
+        }
+    };
+}
+
+/// This macro defines a quantity unit (see *init_quantity*) and its inverse.
+/// The exponents of the inverse are negated.
+///
+/// # Example:
+///
+/// ```
+/// # #[macro_use] extern crate simple_units;
+/// # fn main() {
+/// init_quantity_and_inverse!(Meter, PerMeter, P1, Z0, Z0, Z0, Z0, Z0, Z0);
+/// init_quantity_and_inverse!(MeterPerSecond, SecondPerMeter, P1, Z0, N1, Z0, Z0, Z0, Z0);
+///
+/// assert_eq!(1.0 / MeterPerSecond(4.0), SecondPerMeter(0.25));
+/// assert_eq!(Meter(2.0) * PerMeter(3.0), 6.0);
+/// # }
+/// ```
+#[macro_export] macro_rules! init_quantity_and_inverse {
+    ($unit:ident, $per_unit:ident, $l:ident, $m:ident, $t:ident, $th:ident, $n:ident, $i:ident, $j:ident) => {
+        $crate::init_quantity!($unit, $l, $m, $t, $th, $n, $i, $j);
+        $crate::init_quantity!($per_unit, $crate::dimension::Dim<$crate::negate_exponent!($l), $crate::negate_exponent!($m),
+            $crate::negate_exponent!($t), $crate::negate_exponent!($th), $crate::negate_exponent!($n),
+            $crate::negate_exponent!($i), $crate::negate_exponent!($j)>);
+    };
+}
+
+/// Helper macro for *init_quantity_and_inverse*: maps an exponent to its negated exponent
+#[doc(hidden)]
+#[macro_export] macro_rules! negate_exponent {
+    (Z0) => { $crate::dimension::Z0 };
+    (P1) => { $crate::dimension::N1 };
+    (P2) => { $crate::dimension::N2 };
+    (P3) => { $crate::dimension::N3 };
+    (P4) => { $crate::dimension::N4 };
+    (P5) => { $crate::dimension::N5 };
+    (P6) => { $crate::dimension::N6 };
+    (N1) => { $crate::dimension::P1 };
+    (N2) => { $crate::dimension::P2 };
+    (N3) => { $crate::dimension::P3 };
+    (N4) => { $crate::dimension::P4 };
+    (N5) => { $crate::dimension::P5 };
+    (N6) => { $crate::dimension::P6 };
+}
+
+/// Asserts that two units are approximately equal, see module *approx*.
+/// The tolerance is given as *abs*, *rel* or *ulps*, on failure both values are printed with their units.
+///
+/// # Example:
+///
+/// ```
+/// # #[macro_use] extern crate simple_units;
+/// # fn main() {
+/// use simple_units::si_units::*;
+///
+/// assert_unit_eq!(Meter(1.0) / 3.0 * 3.0, Meter(1.0), rel = 1.0e-12);
+/// assert_unit_eq!(Meter(1.0), Meter(1.001), abs = 0.01);
+/// assert_unit_eq!(Second(0.1) + Second(0.2), Second(0.3), ulps = 1);
+/// # }
+/// ```
+///
+/// ```should_panic
+/// # #[macro_use] extern crate simple_units;
+/// # fn main() {
+/// use simple_units::si_units::*;
+///
+/// // assertion failed: `left ≈ right` (rel = 1e-12)
+/// //   left: 1 m
+/// //  right: 1.001 m
+/// assert_unit_eq!(Meter(1.0), Meter(1.001), rel = 1.0e-12);
+/// # }
+/// ```
+#[macro_export] macro_rules! assert_unit_eq {
+    ($left:expr, $right:expr, abs = $tolerance:expr) => {
+        $crate::assert_unit_eq!($left, $right, $crate::approx::Tolerance::Absolute($tolerance))
+    };
+
+    ($left:expr, $right:expr, rel = $tolerance:expr) => {
+        $crate::assert_unit_eq!($left, $right, $crate::approx::Tolerance::Relative($tolerance))
+    };
+
+    ($left:expr, $right:expr, ulps = $tolerance:expr) => {
+        $crate::assert_unit_eq!($left, $right, $crate::approx::Tolerance::Ulps($tolerance))
+    };
+
+    ($left:expr, $right:expr, $tolerance:expr) => {
+        match (&$left, &$right, $tolerance) {
+            (left, right, tolerance) => {
+                if !$crate::approx::ApproxEq::approx_eq(*left, *right, tolerance) {
+                    ::std::panic!("assertion failed: `left ≈ right` ({})\n  left: {}\n right: {}", tolerance, left, right);
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two units are not approximately equal, the counterpart of *assert_unit_eq*
+///
+/// # Example:
+///
+/// ```
+/// # #[macro_use] extern crate simple_units;
+/// # fn main() {
+/// use simple_units::si_units::*;
+///
+/// assert_unit_ne!(Meter(1.0), Meter(1.001), rel = 1.0e-12);
+/// # }
+/// ```
+#[macro_export] macro_rules! assert_unit_ne {
+    ($left:expr, $right:expr, abs = $tolerance:expr) => {
+        $crate::assert_unit_ne!($left, $right, $crate::approx::Tolerance::Absolute($tolerance))
+    };
+
+    ($left:expr, $right:expr, rel = $tolerance:expr) => {
+        $crate::assert_unit_ne!($left, $right, $crate::approx::Tolerance::Relative($tolerance))
+    };
+
+    ($left:expr, $right:expr, ulps = $tolerance:expr) => {
+        $crate::assert_unit_ne!($left, $right, $crate::approx::Tolerance::Ulps($tolerance))
+    };
+
+    ($left:expr, $right:expr, $tolerance:expr) => {
+        match (&$left, &$right, $tolerance) {
+            (left, right, tolerance) => {
+                if $crate::approx::ApproxEq::approx_eq(*left, *right, tolerance) {
+                    ::std::panic!("assertion failed: `left ≉ right` ({})\n  left: {}\n right: {}", tolerance, left, right);
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,119 @@
+//! simple_units: a simple unit system for Rust
+//!
+//! Written by Willi Kappler, Version 0.1 (2017.02.22)
+//!
+//! Repository: https://github.com/willi-kappler/simple_units
+//!
+//! License: MIT
+//!
+//! Command line unit converter:
+//!
+//! simple_units convert "300 degC" K
+//!
+//! simple_units check "J/(K mol) * K^2 / (J/mol * K/s)"
+
+extern crate simple_units;
+
+use std::env;
+use std::process;
+
+use simple_units::dimension::BASE_QUANTITIES;
+use simple_units::display::{dimension_symbol, DisplayStyle};
+use simple_units::parse::{parse_quantity, parse_unit_expression, ParseError, UnitExpression};
+use simple_units::registry;
+
+const USAGE: &str = "usage:
+    simple_units convert <quantity> <unit>    convert a quantity: convert \"300 degC\" K
+    simple_units check <unit expression>      show size and dimension: check \"J/(K mol) * K^2\"";
+
+// ISO 80000 dimension symbols, in the order of the exponents
+const DIMENSION_SYMBOLS: [&str; BASE_QUANTITIES] = ["L", "M", "T", "Θ", "N", "I", "J"];
+
+// Dimension as product of powers: "L T^-1", "1" for dimensionless
+fn dimension_formula(exponents: [i32; BASE_QUANTITIES]) -> String {
+    let factors: Vec<String> = DIMENSION_SYMBOLS.iter().zip(exponents.iter())
+        .filter(|&(_, &exponent)| exponent != 0)
+        .map(|(symbol, &exponent)| if exponent == 1 {
+            symbol.to_string()
+        } else {
+            format!("{}^{}", symbol, exponent)
+        })
+        .collect();
+
+    if factors.is_empty() {
+        "1".to_string()
+    } else {
+        factors.join(" ")
+    }
+}
+
+// "Θ (K, degC, degF, ...)", with the symbols of the registered units of this dimension
+fn describe_dimension(exponents: [i32; BASE_QUANTITIES]) -> String {
+    let mut symbols: Vec<&str> = registry::with_dimension(exponents)
+        .map(|unit| unit.symbol)
+        .filter(|symbol| !symbol.is_empty())
+        .collect();
+    symbols.dedup();
+
+    if symbols.is_empty() {
+        dimension_formula(exponents)
+    } else {
+        format!("{} ({})", dimension_formula(exponents), symbols.join(", "))
+    }
+}
+
+// A unit expression or the name of a registered unit: "m/s", "seconds", "degrees Celsius"
+fn parse_unit(input: &str) -> Result<UnitExpression, ParseError> {
+    parse_unit_expression(input).or_else(|error| {
+        registry::lookup(input.trim()).map(|unit| unit.expression()).ok_or(error)
+    })
+}
+
+fn convert(quantity: &str, unit: &str) -> Result<String, String> {
+    let parsed = parse_quantity(quantity).map_err(|e| format!("{}: {}", quantity, e))?;
+    let target = parse_unit(unit).map_err(|e| format!("{}: {}", unit, e))?;
+
+    match parsed.value_in(&target) {
+        Ok(value) => Ok(format!("{} = {} {}\ndimension: {}", quantity.trim(), value, unit.trim(),
+            describe_dimension(target.exponents))),
+        Err(ParseError::DimensionMismatch { expected, found }) =>
+            Err(format!("incompatible units: '{}' has dimension {}, '{}' has dimension {}",
+                quantity.trim(), dimension_formula(found), unit.trim(), dimension_formula(expected))),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+fn check(expression: &str) -> Result<String, String> {
+    let unit = parse_unit(expression).map_err(|e| format!("{}: {}", expression, e))?;
+    let symbol = dimension_symbol(unit.exponents, DisplayStyle::Ascii);
+
+    let result = if symbol.is_empty() {
+        unit.size.to_string()
+    } else {
+        format!("{} {}", unit.size, symbol)
+    };
+
+    Ok(format!("{} = {}\ndimension: {}", expression.trim(), result, describe_dimension(unit.exponents)))
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(|arg| arg.as_str()).collect();
+
+    let result = match args.as_slice() {
+        ["convert", quantity, unit] => convert(quantity, unit),
+        ["check", expression] => check(expression),
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+
+    match result {
+        Ok(output) => println!("{}", output),
+        Err(message) => {
+            eprintln!("error: {}", message);
+            process::exit(1);
+        }
+    }
+}
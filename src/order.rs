@@ -0,0 +1,76 @@
+//! simple_units: a simple unit system for Rust
+//!
+//! Written by Willi Kappler, Version 0.1 (2017.02.22)
+//!
+//! Repository: https://github.com/willi-kappler/simple_units
+//!
+//! License: MIT
+//!
+//! Total ordering of units.
+//!
+//! Every unit implements *PartialOrd* and has a *total_cmp* method for sorting.
+//! Units with the same dimension can be compared directly: Meter(1.0) > Foot(3.0).
+//! Both sides are converted into the same unit, so the result does not depend on the order.
+//!
+//! *Ordered* wraps a unit so that it implements *Eq* and *Ord* and can be used
+//! as key of a *BTreeMap*:
+//!
+//! ```
+//! use std::collections::BTreeMap;
+//! use simple_units::si_units::*;
+//! use simple_units::order::Ordered;
+//!
+//! let mut samples = BTreeMap::new();
+//! samples.insert(Ordered(Kelvin(300.0)), "warm");
+//! samples.insert(Ordered(Kelvin(250.0)), "cold");
+//!
+//! assert_eq!(samples.values().next(), Some(&"cold"));
+//! ```
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use ::display::UnitDisplay;
+
+/// A unit with a total order (like f64::total_cmp): -NaN < -inf < ... < -0.0 < 0.0 < ... < inf < NaN
+#[derive(Debug, Clone, Copy)]
+pub struct Ordered<U>(pub U);
+
+impl<U> Ordered<U> {
+    /// The wrapped unit
+    pub fn into_inner(self) -> U {
+        self.0
+    }
+}
+
+impl<U: UnitDisplay> PartialEq for Ordered<U> {
+    fn eq(&self, rhs: &Ordered<U>) -> bool {
+        self.cmp(rhs) == Ordering::Equal
+    }
+}
+
+impl<U: UnitDisplay> Eq for Ordered<U> {}
+
+impl<U: UnitDisplay> PartialOrd for Ordered<U> {
+    fn partial_cmp(&self, rhs: &Ordered<U>) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl<U: UnitDisplay> Ord for Ordered<U> {
+    fn cmp(&self, rhs: &Ordered<U>) -> Ordering {
+        self.0.display_value().total_cmp(&rhs.0.display_value())
+    }
+}
+
+impl<U: fmt::Display> fmt::Display for Ordered<U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<U> From<U> for Ordered<U> {
+    fn from(unit: U) -> Ordered<U> {
+        Ordered(unit)
+    }
+}
@@ -0,0 +1,566 @@
+//! simple_units: a simple unit system for Rust
+//!
+//! Written by Willi Kappler, Version 0.1 (2017.02.22)
+//!
+//! Repository: https://github.com/willi-kappler/simple_units
+//!
+//! License: MIT
+//!
+//! Parse quantities from strings like "500 um", "2.0e-13 m^2/s" or "105 kJ/mol".
+//!
+//! A unit expression is a product of unit symbols (optionally prefixed and with an
+//! integer exponent), separated by "*", "·", "/" or white space. Parentheses can be used
+//! to group sub expressions: "J/(K mol)".
+
+use std::str::FromStr;
+use std::fmt;
+use std::error::Error;
+
+use ::dimension::{Dimension, BASE_QUANTITIES};
+use ::quantity::Quantity;
+use ::si_units::Kelvin;
+use ::conversion::*;
+
+/// Error when parsing a quantity or a unit expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// The numeric value could not be parsed
+    InvalidNumber(String),
+    /// The unit symbol is not known
+    UnknownUnit(String),
+    /// The unit expression is malformed
+    InvalidExpression(String),
+    /// The dimension of the parsed unit does not match the dimension of the target type
+    DimensionMismatch {
+        expected: [i32; BASE_QUANTITIES],
+        found: [i32; BASE_QUANTITIES],
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseError::InvalidNumber(ref s) => write!(f, "invalid number: '{}'", s),
+            ParseError::UnknownUnit(ref s) => write!(f, "unknown unit: '{}'", s),
+            ParseError::InvalidExpression(ref s) => write!(f, "invalid unit expression: '{}'", s),
+            ParseError::DimensionMismatch { expected, found } =>
+                write!(f, "dimension mismatch: expected {:?}, found {:?}", expected, found),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// A parsed unit expression
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnitExpression {
+    /// Size of the unit in SI base units
+    pub size: f64,
+    /// Exponents of the dimension (length, mass, time, temperature, amount, current, luminosity)
+    pub exponents: [i32; BASE_QUANTITIES],
+    /// Zero point of the scale in SI base units, only for absolute temperatures (degC, degF)
+    pub offset: f64,
+}
+
+impl UnitExpression {
+    fn dimensionless(size: f64) -> UnitExpression {
+        UnitExpression { size, exponents: [0; BASE_QUANTITIES], offset: 0.0 }
+    }
+
+    // None if an exponent overflows
+    fn mul(self, rhs: UnitExpression) -> Option<UnitExpression> {
+        let mut exponents = self.exponents;
+        for (e, r) in exponents.iter_mut().zip(rhs.exponents.iter()) {
+            *e = e.checked_add(*r)?;
+        }
+        Some(UnitExpression { size: self.size * rhs.size, exponents, offset: 0.0 })
+    }
+
+    // None if an exponent overflows, the offset of degC is kept for "degC^1"
+    fn powi(self, n: i32) -> Option<UnitExpression> {
+        let mut exponents = self.exponents;
+        for e in exponents.iter_mut() {
+            *e = e.checked_mul(n)?;
+        }
+        let offset = if n == 1 { self.offset } else { 0.0 };
+        Some(UnitExpression { size: self.size.powi(n), exponents, offset })
+    }
+}
+
+/// A number together with its unit expression
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParsedQuantity {
+    /// The number as written in the input
+    pub value: f64,
+    /// The unit of the number
+    pub unit: UnitExpression,
+}
+
+impl ParsedQuantity {
+    /// The value in SI base units, the offset of an absolute temperature is ignored.
+    /// Use this for differences ("10 degC" gives 10 K)
+    pub fn si_value(&self) -> f64 {
+        self.value * self.unit.size
+    }
+
+    /// The value in SI base units including the offset of an absolute temperature.
+    /// ("10 degC" gives 283.15 K)
+    pub fn absolute_si_value(&self) -> f64 {
+        self.value * self.unit.size + self.unit.offset
+    }
+
+    /// The value in the given unit, offsets of absolute temperatures are applied:
+    /// "300 degC" in "K" gives 573.15
+    pub fn value_in(&self, unit: &UnitExpression) -> Result<f64, ParseError> {
+        self.check_dimension(unit.exponents)?;
+        Ok((self.absolute_si_value() - unit.offset) / unit.size)
+    }
+
+    /// Checks that the parsed dimension matches the given exponents
+    pub fn check_dimension(&self, expected: [i32; BASE_QUANTITIES]) -> Result<(), ParseError> {
+        if self.unit.exponents == expected {
+            Ok(())
+        } else {
+            Err(ParseError::DimensionMismatch { expected, found: self.unit.exponents })
+        }
+    }
+}
+
+struct UnitSymbol {
+    symbol: &'static str,
+    size: f64,
+    exponents: [i32; BASE_QUANTITIES],
+    offset: f64,
+    prefix: bool,
+}
+
+const fn unit_symbol(symbol: &'static str, size: f64, exponents: [i32; BASE_QUANTITIES], prefix: bool) -> UnitSymbol {
+    UnitSymbol { symbol, size, exponents, offset: 0.0, prefix }
+}
+
+//                         L  M  T  Θ  N  I  J
+const DIMENSIONLESS: [i32; 7] = [0, 0, 0, 0, 0, 0, 0];
+const LENGTH: [i32; 7] = [1, 0, 0, 0, 0, 0, 0];
+const AREA: [i32; 7] = [2, 0, 0, 0, 0, 0, 0];
+const MASS: [i32; 7] = [0, 1, 0, 0, 0, 0, 0];
+const TIME: [i32; 7] = [0, 0, 1, 0, 0, 0, 0];
+const TEMPERATURE: [i32; 7] = [0, 0, 0, 1, 0, 0, 0];
+const AMOUNT: [i32; 7] = [0, 0, 0, 0, 1, 0, 0];
+const CURRENT: [i32; 7] = [0, 0, 0, 0, 0, 1, 0];
+const LUMINOSITY: [i32; 7] = [0, 0, 0, 0, 0, 0, 1];
+const FORCE: [i32; 7] = [1, 1, -2, 0, 0, 0, 0];
+const PRESSURE: [i32; 7] = [-1, 1, -2, 0, 0, 0, 0];
+const ENERGY: [i32; 7] = [2, 1, -2, 0, 0, 0, 0];
+const POWER: [i32; 7] = [2, 1, -3, 0, 0, 0, 0];
+const FREQUENCY: [i32; 7] = [0, 0, -1, 0, 0, 0, 0];
+const DOSE: [i32; 7] = [2, 0, -2, 0, 0, 0, 0];
+const CATALYTIC_ACTIVITY: [i32; 7] = [0, 0, -1, 0, 1, 0, 0];
+const CHARGE: [i32; 7] = [0, 0, 1, 0, 0, 1, 0];
+const VOLTAGE: [i32; 7] = [2, 1, -3, 0, 0, -1, 0];
+const RESISTANCE: [i32; 7] = [2, 1, -3, 0, 0, -2, 0];
+const CONDUCTANCE: [i32; 7] = [-2, -1, 3, 0, 0, 2, 0];
+const CAPACITANCE: [i32; 7] = [-2, -1, 4, 0, 0, 2, 0];
+const INDUCTANCE: [i32; 7] = [2, 1, -2, 0, 0, -2, 0];
+const MAGNETIC_FLUX: [i32; 7] = [2, 1, -2, 0, 0, -1, 0];
+const MAGNETIC_FLUX_DENSITY: [i32; 7] = [0, 1, -2, 0, 0, -1, 0];
+
+const UNIT_SYMBOLS: &[UnitSymbol] = &[
+    unit_symbol("m", 1.0, LENGTH, true),
+    unit_symbol("g", 1.0e-3, MASS, true),
+    unit_symbol("s", 1.0, TIME, true),
+    unit_symbol("K", 1.0, TEMPERATURE, true),
+    unit_symbol("mol", 1.0, AMOUNT, true),
+    unit_symbol("A", 1.0, CURRENT, true),
+    unit_symbol("cd", 1.0, LUMINOSITY, true),
+    unit_symbol("N", 1.0, FORCE, true),
+    unit_symbol("Pa", 1.0, PRESSURE, true),
+    unit_symbol("J", 1.0, ENERGY, true),
+    unit_symbol("W", 1.0, POWER, true),
+    unit_symbol("Hz", 1.0, FREQUENCY, true),
+    unit_symbol("Bq", 1.0, FREQUENCY, true),
+    unit_symbol("Gy", 1.0, DOSE, true),
+    unit_symbol("Sv", 1.0, DOSE, true),
+    unit_symbol("kat", 1.0, CATALYTIC_ACTIVITY, true),
+    unit_symbol("C", 1.0, CHARGE, true),
+    unit_symbol("V", 1.0, VOLTAGE, true),
+    unit_symbol("Ohm", 1.0, RESISTANCE, true),
+    unit_symbol("Ω", 1.0, RESISTANCE, true),
+    unit_symbol("S", 1.0, CONDUCTANCE, true),
+    unit_symbol("F", 1.0, CAPACITANCE, true),
+    unit_symbol("H", 1.0, INDUCTANCE, true),
+    unit_symbol("Wb", 1.0, MAGNETIC_FLUX, true),
+    unit_symbol("T", 1.0, MAGNETIC_FLUX_DENSITY, true),
+    unit_symbol("rad", 1.0, DIMENSIONLESS, true),
+    unit_symbol("sr", 1.0, DIMENSIONLESS, true),
+    unit_symbol("deg", DEGREE_IN_RADIANS, DIMENSIONLESS, false),
+    unit_symbol("°", DEGREE_IN_RADIANS, DIMENSIONLESS, false),
+    unit_symbol("gon", GRADIAN_IN_RADIANS, DIMENSIONLESS, false),
+    unit_symbol("arcmin", ARCMINUTE_IN_RADIANS, DIMENSIONLESS, false),
+    unit_symbol("arcsec", ARCSECOND_IN_RADIANS, DIMENSIONLESS, false),
+    unit_symbol("ft", FOOT_IN_METERS, LENGTH, false),
+    unit_symbol("yd", YARD_IN_METERS, LENGTH, false),
+    unit_symbol("mi", MILE_IN_METERS, LENGTH, false),
+    unit_symbol("au", ASTRONOMICALUNIT_IN_METERS, LENGTH, false),
+    unit_symbol("ly", LIGHTYEAR_IN_METERS, LENGTH, false),
+    unit_symbol("pc", PARSEC_IN_METERS, LENGTH, false),
+    unit_symbol("t", 1.0e3, MASS, false),
+    unit_symbol("b", 1.0e-28, AREA, false),
+    unit_symbol("min", MINUTE_IN_SECONDS, TIME, false),
+    unit_symbol("h", HOUR_IN_SECONDS, TIME, false),
+    unit_symbol("d", DAY_IN_SECONDS, TIME, false),
+    unit_symbol("wk", WEEK_IN_SECONDS, TIME, false),
+    // Only the prefixes used in geology, "ha" is not a hundred years
+    unit_symbol("a", YEAR_IN_SECONDS, TIME, false),
+    unit_symbol("ka", 1.0e3 * YEAR_IN_SECONDS, TIME, false),
+    unit_symbol("Ma", 1.0e6 * YEAR_IN_SECONDS, TIME, false),
+    unit_symbol("Ga", 1.0e9 * YEAR_IN_SECONDS, TIME, false),
+    unit_symbol("month", MONTH_IN_SECONDS, TIME, false),
+    unit_symbol("yr", YEAR_IN_SECONDS, TIME, false),
+    UnitSymbol { symbol: "degC", size: DEGC_DELTA_IN_KELVIN, exponents: TEMPERATURE, offset: DEGC_TO_KELVIN, prefix: false },
+    UnitSymbol { symbol: "°C", size: DEGC_DELTA_IN_KELVIN, exponents: TEMPERATURE, offset: DEGC_TO_KELVIN, prefix: false },
+    UnitSymbol { symbol: "degF", size: FAHRENHEIT_DELTA_IN_KELVIN, exponents: TEMPERATURE, offset: FAHRENHEIT_ZERO_IN_KELVIN, prefix: false },
+    UnitSymbol { symbol: "°F", size: FAHRENHEIT_DELTA_IN_KELVIN, exponents: TEMPERATURE, offset: FAHRENHEIT_ZERO_IN_KELVIN, prefix: false },
+];
+
+// "da" must be checked before "d"
+const PREFIXES: &[(&str, f64)] = &[
+    ("da", ::prefix::DECA),
+    ("Y", ::prefix::YOTTA), ("Z", ::prefix::ZETTA), ("E", ::prefix::EXA), ("P", ::prefix::PETA),
+    ("T", ::prefix::TERA), ("G", ::prefix::GIGA), ("M", ::prefix::MEGA), ("k", ::prefix::KILO),
+    ("h", ::prefix::HECTO), ("d", ::prefix::DECI), ("c", ::prefix::CENTI), ("m", ::prefix::MILLI),
+    ("u", ::prefix::MICRO), ("µ", ::prefix::MICRO), ("μ", ::prefix::MICRO), ("n", ::prefix::NANO),
+    ("p", ::prefix::PICO), ("f", ::prefix::FEMTO), ("a", ::prefix::ATTO), ("z", ::prefix::ZEPTO),
+    ("y", ::prefix::YOCTO),
+];
+
+// Symbols of units that share their dimension with a unit of another kind,
+// they are only accepted by units of their own kind (see *parse_kind_quantity*).
+// Each group is one kind: all angles can be parsed into a Radian
+const KIND_SYMBOLS: &[&[&str]] = &[
+    &["rad", "deg", "°", "gon", "arcmin", "arcsec"],
+    &["sr"], &["Hz"], &["Bq"], &["Gy"], &["Sv"], &["kat"],
+];
+
+// The table entry of a (maybe prefixed) unit symbol and the factor of the prefix
+fn find_symbol(symbol: &str) -> Option<(&'static UnitSymbol, Option<f64>)> {
+    // Exact match first: "Pa" is pascal and not peta-year, "cd" is candela and not centi-day
+    if let Some(unit) = UNIT_SYMBOLS.iter().find(|unit| unit.symbol == symbol) {
+        return Some((unit, None));
+    }
+
+    PREFIXES.iter().filter_map(|&(prefix, factor)| {
+        let rest = symbol.strip_prefix(prefix)?;
+        let unit = UNIT_SYMBOLS.iter().find(|unit| unit.prefix && unit.symbol == rest)?;
+        Some((unit, Some(factor)))
+    }).next()
+}
+
+/// Looks up a single (maybe prefixed) unit symbol: "m", "kJ", "degC", ...
+fn lookup_symbol(symbol: &str) -> Result<UnitExpression, ParseError> {
+    match find_symbol(symbol) {
+        Some((unit, None)) =>
+            Ok(UnitExpression { size: unit.size, exponents: unit.exponents, offset: unit.offset }),
+        Some((unit, Some(factor))) => UnitExpression::dimensionless(factor)
+            .mul(UnitExpression { size: unit.size, exponents: unit.exponents, offset: 0.0 })
+            .ok_or_else(|| ParseError::InvalidExpression(symbol.to_string())),
+        // Other spellings like "micron" or "hr" and names like "meters"
+        None => ::registry::lookup(symbol).map(|unit| unit.expression())
+            .ok_or_else(|| ParseError::UnknownUnit(symbol.to_string())),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Symbol(String),
+    Integer(i32),
+    Power(i32),
+    Mul,
+    Div,
+    Caret,
+    Minus,
+    OpenParen,
+    CloseParen,
+}
+
+fn superscript_digit(c: char) -> Option<i32> {
+    match c {
+        '⁰' => Some(0), '¹' => Some(1), '²' => Some(2), '³' => Some(3), '⁴' => Some(4),
+        '⁵' => Some(5), '⁶' => Some(6), '⁷' => Some(7), '⁸' => Some(8), '⁹' => Some(9),
+        _ => None,
+    }
+}
+
+fn symbol_char(c: char) -> bool {
+    c.is_alphabetic() || c == '°' || c == 'µ' || c == 'Ω' || c == '′' || c == '″'
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '*' || c == '·' || c == '⋅' {
+            chars.next();
+            tokens.push(Token::Mul);
+        } else if c == '/' {
+            chars.next();
+            tokens.push(Token::Div);
+        } else if c == '^' {
+            chars.next();
+            tokens.push(Token::Caret);
+        } else if c == '-' {
+            chars.next();
+            tokens.push(Token::Minus);
+        } else if c == '(' {
+            chars.next();
+            tokens.push(Token::OpenParen);
+        } else if c == ')' {
+            chars.next();
+            tokens.push(Token::CloseParen);
+        } else if c.is_ascii_digit() {
+            let mut number = String::new();
+            while let Some(&d) = chars.peek() {
+                if !d.is_ascii_digit() { break; }
+                number.push(d);
+                chars.next();
+            }
+            let n = number.parse().map_err(|_| ParseError::InvalidExpression(input.to_string()))?;
+            tokens.push(Token::Integer(n));
+        } else if c == '⁻' || superscript_digit(c).is_some() {
+            let mut sign = 1;
+            if c == '⁻' {
+                sign = -1;
+                chars.next();
+            }
+            let mut n: i32 = 0;
+            let mut digits = 0;
+            while let Some(d) = chars.peek().and_then(|&d| superscript_digit(d)) {
+                n = n.checked_mul(10).and_then(|n| n.checked_add(d))
+                    .ok_or_else(|| ParseError::InvalidExpression(input.to_string()))?;
+                digits += 1;
+                chars.next();
+            }
+            if digits == 0 {
+                return Err(ParseError::InvalidExpression(input.to_string()));
+            }
+            tokens.push(Token::Power(sign * n));
+        } else if symbol_char(c) {
+            let mut symbol = String::new();
+            while let Some(&d) = chars.peek() {
+                if !symbol_char(d) { break; }
+                symbol.push(d);
+                chars.next();
+            }
+            tokens.push(Token::Symbol(symbol));
+        } else {
+            return Err(ParseError::InvalidExpression(input.to_string()));
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self) -> ParseError {
+        ParseError::InvalidExpression(self.input.to_string())
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    // expression := factor (("*" | "/" | <space>) factor)*
+    fn expression(&mut self) -> Result<UnitExpression, ParseError> {
+        let mut result = self.factor()?;
+
+        loop {
+            match self.peek() {
+                Some(&Token::Mul) => {
+                    self.next();
+                    let rhs = self.factor()?;
+                    result = result.mul(rhs).ok_or_else(|| self.error())?;
+                }
+                Some(&Token::Div) => {
+                    self.next();
+                    let rhs = self.factor()?.powi(-1).ok_or_else(|| self.error())?;
+                    result = result.mul(rhs).ok_or_else(|| self.error())?;
+                }
+                Some(&Token::Symbol(_)) | Some(&Token::OpenParen) => {
+                    let rhs = self.factor()?;
+                    result = result.mul(rhs).ok_or_else(|| self.error())?;
+                }
+                _ => return Ok(result),
+            }
+        }
+    }
+
+    // factor := primary ("^" ["-"] integer | superscript)?
+    fn factor(&mut self) -> Result<UnitExpression, ParseError> {
+        let primary = self.primary()?;
+
+        match self.peek() {
+            Some(&Token::Caret) => {
+                self.next();
+                let sign = if self.peek() == Some(&Token::Minus) {
+                    self.next();
+                    -1
+                } else {
+                    1
+                };
+                match self.next() {
+                    Some(Token::Integer(n)) => primary.powi(sign * n).ok_or_else(|| self.error()),
+                    _ => Err(self.error()),
+                }
+            }
+            Some(&Token::Power(n)) => {
+                self.next();
+                primary.powi(n).ok_or_else(|| self.error())
+            }
+            _ => Ok(primary),
+        }
+    }
+
+    // primary := symbol | "1" | "(" expression ")"
+    fn primary(&mut self) -> Result<UnitExpression, ParseError> {
+        match self.next() {
+            Some(Token::Symbol(symbol)) => lookup_symbol(&symbol),
+            // Only the 1 of "1/s" or a lone "1", other numbers are a mistake: "1 000 m"
+            Some(Token::Integer(1)) if self.peek() == Some(&Token::Div) || self.tokens.len() == 1 =>
+                Ok(UnitExpression::dimensionless(1.0)),
+            // A single unit keeps its offset: "(degC)", products are differences: "J/(kg degC)"
+            Some(Token::OpenParen) => {
+                let result = self.expression()?;
+                match self.next() {
+                    Some(Token::CloseParen) => Ok(result),
+                    _ => Err(self.error()),
+                }
+            }
+            _ => Err(self.error()),
+        }
+    }
+}
+
+/// Parses a unit expression like "m^2/s" or "kJ/mol".
+/// An empty expression is dimensionless.
+pub fn parse_unit_expression(input: &str) -> Result<UnitExpression, ParseError> {
+    let tokens = tokenize(input)?;
+
+    if tokens.is_empty() {
+        return Ok(UnitExpression::dimensionless(1.0));
+    }
+
+    let mut parser = Parser { input, tokens, position: 0 };
+    let result = parser.expression()?;
+
+    if parser.position == parser.tokens.len() {
+        Ok(result)
+    } else {
+        Err(parser.error())
+    }
+}
+
+/// Splits "500 um" into the number and the (not yet parsed) unit expression "um"
+pub fn split_quantity(input: &str) -> Result<(f64, &str), ParseError> {
+    let input = input.trim();
+
+    // The longest prefix that is a valid number
+    let number_end = input.char_indices().map(|(i, c)| i + c.len_utf8())
+        .rev()
+        .find(|&end| input[..end].parse::<f64>().is_ok())
+        .ok_or_else(|| ParseError::InvalidNumber(input.to_string()))?;
+
+    let value = input[..number_end].parse::<f64>()
+        .map_err(|_| ParseError::InvalidNumber(input.to_string()))?;
+
+    Ok((value, input[number_end..].trim_start()))
+}
+
+/// Parses a number followed by a unit expression: "500 um", "2.0e-13 m^2/s"
+pub fn parse_quantity(input: &str) -> Result<ParsedQuantity, ParseError> {
+    let (value, unit) = split_quantity(input)?;
+    let unit = parse_unit_expression(unit)?;
+
+    Ok(ParsedQuantity { value, unit })
+}
+
+/// Parses a quantity that may only use units of its own kind: "5 sr" is not a *Radian*
+/// and "5 Bq" is not a *Hertz*. The symbols stand for the kinds of the target unit
+/// ("rad" allows all angles), symbols of other kinds are rejected with *UnknownUnit*.
+/// Used by *parse_unit* and, without symbols, by the quantities: "5 Hz" is not a *PerSecond*
+pub fn parse_kind_quantity<D: Dimension>(input: &str, symbols: &[&str]) -> Result<Quantity<D>, ParseError> {
+    let (_, unit) = split_quantity(input)?;
+    check_kind_symbols(unit, symbols)?;
+
+    let parsed = parse_quantity(input)?;
+    parsed.check_dimension(D::EXPONENTS)?;
+    Ok(Quantity::new(parsed.si_value()))
+}
+
+// Checks that the unit expression uses only the kinds of the target unit expression:
+// "Bq" can not be read into "Hz", but "1/s" can
+pub(crate) fn check_same_kind(unit: &str, target: &str) -> Result<(), ParseError> {
+    let mut symbols = Vec::new();
+    for token in tokenize(target)? {
+        if let Token::Symbol(symbol) = token {
+            if let Some((unit, _)) = find_symbol(&symbol) {
+                symbols.push(unit.symbol);
+            }
+        }
+    }
+
+    check_kind_symbols(unit, &symbols)
+}
+
+fn check_kind_symbols(unit: &str, symbols: &[&str]) -> Result<(), ParseError> {
+    for token in tokenize(unit)? {
+        if let Token::Symbol(symbol) = token {
+            match find_symbol(&symbol) {
+                Some((unit, _)) => if let Some(kind) = KIND_SYMBOLS.iter().find(|kind| kind.contains(&unit.symbol)) {
+                    if !symbols.iter().any(|allowed| kind.contains(allowed)) {
+                        return Err(ParseError::UnknownUnit(symbol));
+                    }
+                },
+                // An alias or name stands for the symbol of its unit: "grad" is "gon"
+                None => if let Some(unit) = ::registry::lookup(&symbol) {
+                    check_kind_symbols(unit.symbol, symbols)
+                        .map_err(|_| ParseError::UnknownUnit(symbol.clone()))?;
+                },
+            }
+        }
+    }
+
+    Ok(())
+}
+
+impl<D: Dimension> FromStr for Quantity<D> {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Quantity<D>, ParseError> {
+        parse_kind_quantity(input, &[])
+    }
+}
+
+// Absolute temperature: the offset of degC and degF is applied
+impl FromStr for Kelvin {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Kelvin, ParseError> {
+        let parsed = parse_quantity(input)?;
+        parsed.check_dimension(TEMPERATURE)?;
+        Ok(Kelvin(parsed.absolute_si_value()))
+    }
+}
@@ -0,0 +1,46 @@
+//! simple_units: a simple unit system for Rust
+//!
+//! Written by Willi Kappler, Version 0.1 (2017.02.22)
+//!
+//! Repository: https://github.com/willi-kappler/simple_units
+//!
+//! License: MIT
+//!
+//! SI and binary prefixes, use them with *prefix_unit!*
+//!
+//! The binary prefixes are meant for units of information (bit, byte)
+//! and should not be used for SI units.
+
+pub const YOCTO: f64 = 1.0e-24;
+pub const ZEPTO: f64 = 1.0e-21;
+pub const ATTO: f64 = 1.0e-18;
+pub const FEMTO: f64 = 1.0e-15;
+pub const PICO: f64 = 1.0e-12;
+pub const NANO: f64 = 1.0e-9;
+pub const MICRO: f64 = 1.0e-6;
+pub const MILLI: f64 = 1.0e-3;
+pub const CENTI: f64 = 1.0e-2;
+pub const DECI: f64 = 1.0e-1;
+pub const DECA: f64 = 1.0e+1;
+pub const HECTO: f64 = 1.0e+2;
+pub const KILO: f64 = 1.0e+3;
+pub const MEGA: f64 = 1.0e+6;
+pub const GIGA: f64 = 1.0e+9;
+pub const TERA: f64 = 1.0e+12;
+pub const PETA: f64 = 1.0e+15;
+pub const EXA: f64 = 1.0e+18;
+pub const ZETTA: f64 = 1.0e+21;
+pub const YOTTA: f64 = 1.0e+24;
+
+pub const KIBI: f64 = 1024.0;
+pub const MEBI: f64 = KIBI * 1024.0;
+pub const GIBI: f64 = MEBI * 1024.0;
+pub const TEBI: f64 = GIBI * 1024.0;
+pub const PEBI: f64 = TEBI * 1024.0;
+pub const EXBI: f64 = PEBI * 1024.0;
+pub const ZEBI: f64 = EXBI * 1024.0;
+pub const YOBI: f64 = ZEBI * 1024.0;
+
+/// The kilogram is the SI base unit for mass, but the prefixes are applied to the gram.
+/// Use this factor to get the size of a prefixed gram in kilogram: MILLI * GRAM
+pub const GRAM: f64 = MILLI;
@@ -0,0 +1,339 @@
+//! simple_units: a simple unit system for Rust
+//!
+//! Written by Willi Kappler, Version 0.1 (2017.02.22)
+//!
+//! Repository: https://github.com/willi-kappler/simple_units
+//!
+//! License: MIT
+//!
+//! Generic quantity: a value in SI base units tagged with its dimension.
+//! Multiplication and division work for any combination of dimensions.
+
+use std::ops::Add;
+use std::ops::Sub;
+use std::ops::Mul;
+use std::ops::Div;
+use std::ops::{Neg, Rem, AddAssign, SubAssign, MulAssign, DivAssign};
+use std::iter::{Sum, Product};
+use std::cmp::{PartialEq, PartialOrd, Ordering};
+use std::marker::PhantomData;
+use std::fmt;
+
+use typenum::{Integer, P2, P3};
+
+use ::dimension::{Dimension, Dimensionless, DimMul, DimDiv, DimInv, DimProd, DimQuot, DimInverse};
+use ::dimension::{DimPow, DimRoot, DimPower, DimRootOf};
+use ::si_units::Radian;
+use ::kind::Kind;
+
+/// A value in coherent SI units with the dimension D
+///
+/// The units in *si_units* (Meter, Second, Newton, ...) are aliases of this type.
+pub struct Quantity<D>(pub f64, pub PhantomData<D>);
+
+impl<D> Quantity<D> {
+    /// Creates a new quantity from a value given in SI units
+    pub const fn new(value: f64) -> Quantity<D> {
+        Quantity(value, PhantomData)
+    }
+
+    /// Returns the value in SI units
+    pub fn value(self) -> f64 {
+        self.0
+    }
+
+    /// Tags the value with a kind of quantity, see module *kind*:
+    /// (Newton(2.0) * Meter(3.0)).tag::<NewtonMeter>()
+    pub fn tag<K: Kind<Base = Quantity<D>>>(self) -> K {
+        K::tag(self)
+    }
+
+    /// Square root, only for dimensions with even exponents:
+    /// Meter2(4.0).sqrt() == Meter(2.0)
+    ///
+    /// The square root of a length does not compile:
+    ///
+    /// ```compile_fail
+    /// # extern crate simple_units;
+    /// # use simple_units::si_units::*;
+    /// # fn main() {
+    /// let root = Meter(4.0).sqrt();
+    /// # }
+    /// ```
+    pub fn sqrt(self) -> Quantity<DimRootOf<D, P2>> where D: DimRoot<P2> {
+        Quantity::new(self.0.sqrt())
+    }
+
+    /// Cube root, only for dimensions with exponents divisible by three:
+    /// Meter3(8.0).cbrt() == Meter(2.0)
+    pub fn cbrt(self) -> Quantity<DimRootOf<D, P3>> where D: DimRoot<P3> {
+        Quantity::new(self.0.cbrt())
+    }
+
+    /// Integer power, the exponent is a typenum integer:
+    /// Meter(2.0).powi::<P3>() == Meter3(8.0)
+    pub fn powi<N: Integer>(self) -> Quantity<DimPower<D, N>> where D: DimPow<N> {
+        Quantity::new(self.0.powi(N::I32))
+    }
+}
+
+// The methods of f64 that keep the dimension
+impl<D> Quantity<D> {
+    /// Absolute value
+    pub fn abs(self) -> Quantity<D> {
+        Quantity::new(self.0.abs())
+    }
+
+    /// Sign of the value: 1.0, -1.0 or NaN
+    pub fn signum(self) -> f64 {
+        self.0.signum()
+    }
+
+    /// Minimum of two values, NaN is ignored
+    pub fn min(self, other: Quantity<D>) -> Quantity<D> {
+        Quantity::new(self.0.min(other.0))
+    }
+
+    /// Maximum of two values, NaN is ignored
+    pub fn max(self, other: Quantity<D>) -> Quantity<D> {
+        Quantity::new(self.0.max(other.0))
+    }
+
+    /// Restricts the value to the interval [min, max]
+    pub fn clamp(self, min: Quantity<D>, max: Quantity<D>) -> Quantity<D> {
+        Quantity::new(self.0.clamp(min.0, max.0))
+    }
+
+    /// Largest integer value less than or equal to the value
+    pub fn floor(self) -> Quantity<D> {
+        Quantity::new(self.0.floor())
+    }
+
+    /// Smallest integer value greater than or equal to the value
+    pub fn ceil(self) -> Quantity<D> {
+        Quantity::new(self.0.ceil())
+    }
+
+    /// Nearest integer value, half-way cases away from zero
+    pub fn round(self) -> Quantity<D> {
+        Quantity::new(self.0.round())
+    }
+
+    /// True if the value is NaN
+    pub fn is_nan(self) -> bool {
+        self.0.is_nan()
+    }
+
+    /// True if the value is neither infinite nor NaN
+    pub fn is_finite(self) -> bool {
+        self.0.is_finite()
+    }
+
+    /// Total ordering like f64::total_cmp, for sorting values that may be NaN
+    pub fn total_cmp(&self, other: &Quantity<D>) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+
+    /// Length of the hypotenuse: sqrt(self^2 + other^2)
+    pub fn hypot(self, other: Quantity<D>) -> Quantity<D> {
+        Quantity::new(self.0.hypot(other.0))
+    }
+
+    /// Fused multiply add: self * factor + offset
+    pub fn mul_add(self, factor: f64, offset: Quantity<D>) -> Quantity<D> {
+        Quantity::new(self.0.mul_add(factor, offset.0))
+    }
+
+    /// Angle of the point (other, self), like f64::atan2
+    pub fn atan2(self, other: Quantity<D>) -> Radian {
+        Radian(self.0.atan2(other.0))
+    }
+}
+
+impl Quantity<Dimensionless> {
+    /// Natural logarithm of a dimensionless quantity
+    pub fn ln(self) -> f64 {
+        self.0.ln()
+    }
+
+    /// Exponential function of a dimensionless quantity
+    pub fn exp(self) -> f64 {
+        self.0.exp()
+    }
+}
+
+impl<D> Clone for Quantity<D> {
+    fn clone(&self) -> Quantity<D> {
+        *self
+    }
+}
+
+impl<D> Copy for Quantity<D> {}
+
+impl<D: Dimension> fmt::Debug for Quantity<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Quantity({:?}, {:?})", self.0, D::EXPONENTS)
+    }
+}
+
+impl<D> PartialEq for Quantity<D> {
+    fn eq(&self, rhs: &Quantity<D>) -> bool {
+        self.0 == rhs.0
+    }
+}
+
+impl PartialEq<f64> for Quantity<Dimensionless> {
+    fn eq(&self, rhs: &f64) -> bool {
+        self.0 == *rhs
+    }
+}
+
+impl<D> PartialOrd for Quantity<D> {
+    fn partial_cmp(&self, rhs: &Quantity<D>) -> Option<Ordering> {
+        self.0.partial_cmp(&rhs.0)
+    }
+}
+
+impl PartialOrd<f64> for Quantity<Dimensionless> {
+    fn partial_cmp(&self, rhs: &f64) -> Option<Ordering> {
+        self.0.partial_cmp(rhs)
+    }
+}
+
+impl From<Quantity<Dimensionless>> for f64 {
+    fn from(value: Quantity<Dimensionless>) -> f64 {
+        value.0
+    }
+}
+
+impl<D> Add for Quantity<D> {
+    type Output = Quantity<D>;
+
+    fn add(self, rhs: Quantity<D>) -> Quantity<D> {
+        Quantity::new(self.0 + rhs.0)
+    }
+}
+
+impl<D> Sub for Quantity<D> {
+    type Output = Quantity<D>;
+
+    fn sub(self, rhs: Quantity<D>) -> Quantity<D> {
+        Quantity::new(self.0 - rhs.0)
+    }
+}
+
+impl<D> Mul<f64> for Quantity<D> {
+    type Output = Quantity<D>;
+
+    fn mul(self, rhs: f64) -> Quantity<D> {
+        Quantity::new(self.0 * rhs)
+    }
+}
+
+impl<D> Mul<Quantity<D>> for f64 {
+    type Output = Quantity<D>;
+
+    fn mul(self, rhs: Quantity<D>) -> Quantity<D> {
+        Quantity::new(self * rhs.0)
+    }
+}
+
+impl<D> Div<f64> for Quantity<D> {
+    type Output = Quantity<D>;
+
+    fn div(self, rhs: f64) -> Quantity<D> {
+        Quantity::new(self.0 / rhs)
+    }
+}
+
+// 1 / A
+impl<D: DimInv> Div<Quantity<D>> for f64 {
+    type Output = Quantity<DimInverse<D>>;
+
+    fn div(self, rhs: Quantity<D>) -> Quantity<DimInverse<D>> {
+        Quantity::new(self / rhs.0)
+    }
+}
+
+// A * B, exponents are added
+impl<D1, D2> Mul<Quantity<D2>> for Quantity<D1> where D1: DimMul<D2> {
+    type Output = Quantity<DimProd<D1, D2>>;
+
+    fn mul(self, rhs: Quantity<D2>) -> Quantity<DimProd<D1, D2>> {
+        Quantity::new(self.0 * rhs.0)
+    }
+}
+
+// A / B, exponents are subtracted
+impl<D1, D2> Div<Quantity<D2>> for Quantity<D1> where D1: DimDiv<D2> {
+    type Output = Quantity<DimQuot<D1, D2>>;
+
+    fn div(self, rhs: Quantity<D2>) -> Quantity<DimQuot<D1, D2>> {
+        Quantity::new(self.0 / rhs.0)
+    }
+}
+
+impl<D> Neg for Quantity<D> {
+    type Output = Quantity<D>;
+
+    fn neg(self) -> Quantity<D> {
+        Quantity::new(-self.0)
+    }
+}
+
+impl<D> AddAssign for Quantity<D> {
+    fn add_assign(&mut self, rhs: Quantity<D>) {
+        self.0 += rhs.0;
+    }
+}
+
+impl<D> SubAssign for Quantity<D> {
+    fn sub_assign(&mut self, rhs: Quantity<D>) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl<D> MulAssign<f64> for Quantity<D> {
+    fn mul_assign(&mut self, rhs: f64) {
+        self.0 *= rhs;
+    }
+}
+
+impl<D> DivAssign<f64> for Quantity<D> {
+    fn div_assign(&mut self, rhs: f64) {
+        self.0 /= rhs;
+    }
+}
+
+impl<D> Rem for Quantity<D> {
+    type Output = Quantity<D>;
+
+    fn rem(self, rhs: Quantity<D>) -> Quantity<D> {
+        Quantity::new(self.0 % rhs.0)
+    }
+}
+
+impl<D> Sum for Quantity<D> {
+    fn sum<I: Iterator<Item = Quantity<D>>>(iter: I) -> Quantity<D> {
+        Quantity::new(iter.fold(0.0, |sum, value| sum + value.0))
+    }
+}
+
+impl<'a, D> Sum<&'a Quantity<D>> for Quantity<D> {
+    fn sum<I: Iterator<Item = &'a Quantity<D>>>(iter: I) -> Quantity<D> {
+        Quantity::new(iter.fold(0.0, |sum, value| sum + value.0))
+    }
+}
+
+// The product of quantities with a dimension would change the dimension with every factor
+impl Product for Quantity<Dimensionless> {
+    fn product<I: Iterator<Item = Quantity<Dimensionless>>>(iter: I) -> Quantity<Dimensionless> {
+        Quantity::new(iter.map(|value| value.0).product())
+    }
+}
+
+impl<'a> Product<&'a Quantity<Dimensionless>> for Quantity<Dimensionless> {
+    fn product<I: Iterator<Item = &'a Quantity<Dimensionless>>>(iter: I) -> Quantity<Dimensionless> {
+        Quantity::new(iter.map(|value| value.0).product())
+    }
+}
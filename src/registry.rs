@@ -0,0 +1,302 @@
+//! simple_units: a simple unit system for Rust
+//!
+//! Written by Willi Kappler, Version 0.1 (2017.02.22)
+//!
+//! Repository: https://github.com/willi-kappler/simple_units
+//!
+//! License: MIT
+//!
+//! Runtime registry of all units defined in *si_units* and *conversion*.
+//!
+//! The unit types only exist at compile time, the registry lists them with their
+//! name, plural, ASCII symbol and aliases, so that tools can look them up by name:
+//!
+//! ```
+//! use simple_units::registry;
+//!
+//! let unit = registry::lookup("metre").unwrap();
+//! assert_eq!(unit.type_name, "Meter");
+//! assert_eq!(unit.symbol, "m");
+//! assert_eq!(unit.dimension(), [1, 0, 0, 0, 0, 0, 0]);
+//! ```
+//!
+//! Size and dimension are derived from the symbol with *parse::parse_unit_expression*.
+//! The parser also resolves the aliases through this registry: "3 micron" and "2 hr"
+//! can be parsed like "3 um" and "2 h".
+
+use ::dimension::BASE_QUANTITIES;
+use ::parse::{parse_unit_expression, UnitExpression};
+
+/// Description of a unit type
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnitInfo {
+    /// Name of the Rust type: "MeterPerSecond"
+    pub type_name: &'static str,
+    /// Name in words: "meter per second"
+    pub name: &'static str,
+    /// Plural of the name: "meters per second"
+    pub plural: &'static str,
+    /// ASCII symbol as used by *Display*: "m/s"
+    pub symbol: &'static str,
+    /// Other symbols and spellings in one word, all of them can be parsed: "metre", "µm", "°C"
+    pub aliases: &'static [&'static str],
+    /// Other names in several words: "metre per second"
+    pub other_names: &'static [&'static str],
+    /// Absolute temperature (Kelvin, DegC, Fahrenheit), the offset is used for conversion
+    pub absolute: bool,
+}
+
+impl UnitInfo {
+    /// Size, dimension and (for absolute temperatures) offset of this unit
+    pub fn expression(&self) -> UnitExpression {
+        let expression = parse_unit_expression(self.symbol)
+            .expect("registry: every symbol can be parsed");
+
+        if self.absolute {
+            expression
+        } else {
+            UnitExpression { offset: 0.0, .. expression }
+        }
+    }
+
+    /// Exponents of the dimension (length, mass, time, temperature, amount, current, luminosity)
+    pub fn dimension(&self) -> [i32; BASE_QUANTITIES] {
+        self.expression().exponents
+    }
+
+    /// Size of this unit in SI base units
+    pub fn size(&self) -> f64 {
+        self.expression().size
+    }
+
+    /// All names this unit can be found with: symbol, name, plural, aliases, other names and type name
+    pub fn names(&self) -> Vec<&'static str> {
+        let mut names = vec![self.symbol, self.name, self.plural, self.type_name];
+        names.extend_from_slice(self.aliases);
+        names.extend_from_slice(self.other_names);
+        names
+    }
+
+    // Symbols and aliases are case sensitive ("mm" and "Mm")
+    fn matches_symbol(&self, symbol: &str) -> bool {
+        self.symbol == symbol || self.aliases.contains(&symbol)
+    }
+
+    fn matches_name(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name) ||
+        self.plural.eq_ignore_ascii_case(name) ||
+        self.type_name.eq_ignore_ascii_case(name) ||
+        self.other_names.iter().any(|other| other.eq_ignore_ascii_case(name))
+    }
+
+    const fn with_other_names(self, other_names: &'static [&'static str]) -> UnitInfo {
+        UnitInfo { other_names, .. self }
+    }
+}
+
+const fn unit_info(type_name: &'static str, name: &'static str, plural: &'static str,
+    symbol: &'static str, aliases: &'static [&'static str]) -> UnitInfo {
+    UnitInfo { type_name, name, plural, symbol, aliases, other_names: &[], absolute: false }
+}
+
+const fn absolute_unit_info(type_name: &'static str, name: &'static str, plural: &'static str,
+    symbol: &'static str, aliases: &'static [&'static str]) -> UnitInfo {
+    UnitInfo { type_name, name, plural, symbol, aliases, other_names: &[], absolute: true }
+}
+
+// Absolute temperatures come before their differences, so that "K" and "degC" find them first
+const UNITS: &[UnitInfo] = &[
+    // si_units
+    unit_info("Unitless", "unitless", "unitless", "", &["1"]),
+    unit_info("Radian", "radian", "radians", "rad", &[]),
+    unit_info("Steradian", "steradian", "steradians", "sr", &[]),
+
+    unit_info("Meter", "meter", "meters", "m", &["metre", "metres"]),
+    unit_info("PerMeter", "per meter", "per meter", "1/m", &[]).with_other_names(&["per metre"]),
+    unit_info("Meter2", "square meter", "square meters", "m^2", &["m²"]).with_other_names(&["square metre", "square metres"]),
+    unit_info("PerMeter2", "per square meter", "per square meter", "1/m^2", &[]),
+    unit_info("Meter3", "cubic meter", "cubic meters", "m^3", &["m³"]).with_other_names(&["cubic metre", "cubic metres"]),
+    unit_info("PerMeter3", "per cubic meter", "per cubic meter", "1/m^3", &[]),
+
+    unit_info("Second", "second", "seconds", "s", &["sec"]),
+    unit_info("PerSecond", "per second", "per second", "1/s", &[]),
+    unit_info("Second2", "square second", "square seconds", "s^2", &["s²"]),
+    unit_info("PerSecond2", "per square second", "per square second", "1/s^2", &[]),
+    unit_info("Second3", "cubic second", "cubic seconds", "s^3", &["s³"]),
+    unit_info("PerSecond3", "per cubic second", "per cubic second", "1/s^3", &[]),
+
+    unit_info("MeterPerSecond", "meter per second", "meters per second", "m/s", &[]).with_other_names(&["metre per second", "metres per second"]),
+    unit_info("SecondPerMeter", "second per meter", "seconds per meter", "s/m", &[]),
+    unit_info("MeterPerSecond2", "meter per square second", "meters per square second", "m/s^2", &["m/s²"]),
+    unit_info("Second2PerMeter", "square second per meter", "square seconds per meter", "s^2/m", &[]),
+    unit_info("Meter2PerSecond", "square meter per second", "square meters per second", "m^2/s", &["m²/s"]),
+    unit_info("SecondPerMeter2", "second per square meter", "seconds per square meter", "s/m^2", &[]),
+    unit_info("Meter2PerSecond2", "square meter per square second", "square meters per square second", "m^2/s^2", &[]),
+    unit_info("Second2PerMeter2", "square second per square meter", "square seconds per square meter", "s^2/m^2", &[]),
+    unit_info("MeterSecond", "meter second", "meter seconds", "m*s", &[]),
+    unit_info("PerMeterSecond", "per meter second", "per meter second", "1/(m*s)", &[]),
+    unit_info("Meter2Second", "square meter second", "square meter seconds", "m^2*s", &[]),
+    unit_info("PerMeter2Second", "per square meter second", "per square meter second", "1/(m^2*s)", &[]),
+    unit_info("MeterSecond2", "meter square second", "meter square seconds", "m*s^2", &[]),
+    unit_info("PerMeterSecond2", "per meter square second", "per meter square second", "1/(m*s^2)", &[]),
+    unit_info("Meter2Second2", "square meter square second", "square meter square seconds", "m^2*s^2", &[]),
+    unit_info("PerMeter2Second2", "per square meter square second", "per square meter square second", "1/(m^2*s^2)", &[]),
+
+    unit_info("Kilogram", "kilogram", "kilograms", "kg", &["kilogramme", "kilogrammes"]),
+    unit_info("PerKilogram", "per kilogram", "per kilogram", "1/kg", &[]),
+    unit_info("KilogramMeterPerSecond", "kilogram meter per second", "kilogram meters per second", "kg*m/s", &[]),
+    unit_info("SecondPerKilogramMeter", "second per kilogram meter", "seconds per kilogram meter", "s/(kg*m)", &[]),
+    unit_info("Meter3PerKilogramSecond2", "cubic meter per kilogram square second", "cubic meters per kilogram square second", "m^3/(kg*s^2)", &[]),
+    unit_info("KilogramSecond2PerMeter3", "kilogram square second per cubic meter", "kilogram square seconds per cubic meter", "kg*s^2/m^3", &[]),
+    unit_info("Newton", "newton", "newtons", "N", &[]),
+    unit_info("PerNewton", "per newton", "per newton", "1/N", &[]),
+    unit_info("Pascal", "pascal", "pascals", "Pa", &[]),
+    unit_info("PerPascal", "per pascal", "per pascal", "1/Pa", &[]),
+    unit_info("Joule", "joule", "joules", "J", &[]),
+    unit_info("PerJoule", "per joule", "per joule", "1/J", &[]),
+    unit_info("Watt", "watt", "watts", "W", &[]),
+    unit_info("PerWatt", "per watt", "per watt", "1/W", &[]),
+    unit_info("JouleSecond", "joule second", "joule seconds", "J*s", &[]),
+    unit_info("PerJouleSecond", "per joule second", "per joule second", "1/(J*s)", &[]),
+
+    absolute_unit_info("Kelvin", "kelvin", "kelvins", "K", &[]),
+    unit_info("KelvinDelta", "kelvin difference", "kelvin differences", "K", &[]),
+    unit_info("PerKelvin", "per kelvin", "per kelvin", "1/K", &[]),
+    unit_info("Kelvin2", "square kelvin", "square kelvins", "K^2", &["K²"]),
+    unit_info("PerKelvin2", "per square kelvin", "per square kelvin", "1/K^2", &[]),
+    unit_info("KelvinPerSecond", "kelvin per second", "kelvins per second", "K/s", &[]),
+    unit_info("SecondPerKelvin", "second per kelvin", "seconds per kelvin", "s/K", &[]),
+    unit_info("JouleKelvinPerSecond", "joule kelvin per second", "joule kelvins per second", "J*K/s", &[]),
+
+    unit_info("Mol", "mole", "moles", "mol", &[]),
+    unit_info("PerMol", "per mole", "per mole", "1/mol", &[]),
+    unit_info("JoulePerKelvin", "joule per kelvin", "joules per kelvin", "J/K", &[]),
+    unit_info("KelvinPerJoule", "kelvin per joule", "kelvins per joule", "K/J", &[]),
+    unit_info("JoulePerMol", "joule per mole", "joules per mole", "J/mol", &[]),
+    unit_info("MolPerJoule", "mole per joule", "moles per joule", "mol/J", &[]),
+    unit_info("JoulePerKelvinMol", "joule per kelvin mole", "joules per kelvin mole", "J/(K*mol)", &[]),
+    unit_info("KelvinMolPerJoule", "kelvin mole per joule", "kelvin moles per joule", "K*mol/J", &[]),
+    unit_info("JouleKelvinPerMol", "joule kelvin per mole", "joule kelvins per mole", "J*K/mol", &[]),
+    unit_info("MolPerJouleKelvin", "mole per joule kelvin", "moles per joule kelvin", "mol/(J*K)", &[]),
+    unit_info("SecondPerMol", "second per mole", "seconds per mole", "s/mol", &[]),
+    unit_info("MolPerSecond", "mole per second", "moles per second", "mol/s", &[]),
+    unit_info("JouleKelvinPerMolSecond", "joule kelvin per mole second", "joule kelvins per mole second", "J*K/(mol*s)", &[]),
+    unit_info("MolSecondPerJouleKelvin", "mole second per joule kelvin", "mole seconds per joule kelvin", "mol*s/(J*K)", &[]),
+
+    unit_info("Ampere", "ampere", "amperes", "A", &["amp", "amps"]),
+    unit_info("PerAmpere", "per ampere", "per ampere", "1/A", &[]),
+    unit_info("Ampere2", "square ampere", "square amperes", "A^2", &["A²"]),
+    unit_info("PerAmpere2", "per square ampere", "per square ampere", "1/A^2", &[]),
+    unit_info("AmperePerMeter", "ampere per meter", "amperes per meter", "A/m", &[]),
+    unit_info("MeterPerAmpere", "meter per ampere", "meters per ampere", "m/A", &[]),
+    unit_info("AmperePerMeter2", "ampere per square meter", "amperes per square meter", "A/m^2", &[]),
+    unit_info("Meter2PerAmpere", "square meter per ampere", "square meters per ampere", "m^2/A", &[]),
+    unit_info("AmperePerSecond", "ampere per second", "amperes per second", "A/s", &[]),
+    unit_info("SecondPerAmpere", "second per ampere", "seconds per ampere", "s/A", &[]),
+
+    unit_info("Coulomb", "coulomb", "coulombs", "C", &[]),
+    unit_info("PerCoulomb", "per coulomb", "per coulomb", "1/C", &[]),
+    unit_info("Volt", "volt", "volts", "V", &[]),
+    unit_info("PerVolt", "per volt", "per volt", "1/V", &[]),
+    unit_info("VoltPerMeter", "volt per meter", "volts per meter", "V/m", &[]),
+    unit_info("MeterPerVolt", "meter per volt", "meters per volt", "m/V", &[]),
+    unit_info("Ohm", "ohm", "ohms", "Ohm", &["Ω"]),
+    unit_info("Siemens", "siemens", "siemens", "S", &["mho"]),
+    unit_info("Farad", "farad", "farads", "F", &[]),
+    unit_info("PerFarad", "per farad", "per farad", "1/F", &[]),
+    unit_info("Henry", "henry", "henries", "H", &["henrys"]),
+    unit_info("PerHenry", "per henry", "per henry", "1/H", &[]),
+    unit_info("Weber", "weber", "webers", "Wb", &[]),
+    unit_info("PerWeber", "per weber", "per weber", "1/Wb", &[]),
+    unit_info("Tesla", "tesla", "teslas", "T", &[]),
+    unit_info("PerTesla", "per tesla", "per tesla", "1/T", &[]),
+
+    unit_info("JoulePerKilogram", "joule per kilogram", "joules per kilogram", "J/kg", &[]),
+    unit_info("KilogramPerJoule", "kilogram per joule", "kilograms per joule", "kg/J", &[]),
+    unit_info("Hertz", "hertz", "hertz", "Hz", &[]),
+    unit_info("Becquerel", "becquerel", "becquerels", "Bq", &[]),
+    unit_info("RadianPerSecond", "radian per second", "radians per second", "rad/s", &[]),
+    unit_info("Gray", "gray", "grays", "Gy", &[]),
+    unit_info("Sievert", "sievert", "sieverts", "Sv", &[]),
+    unit_info("Katal", "katal", "katals", "kat", &[]),
+    unit_info("NewtonMeter", "newton meter", "newton meters", "N*m", &["N·m"]).with_other_names(&["newton metre", "newton metres"]),
+    unit_info("Barn", "barn", "barns", "b", &[]),
+
+    unit_info("Candela", "candela", "candelas", "cd", &[]),
+    unit_info("PerCandela", "per candela", "per candela", "1/cd", &[]),
+    unit_info("CandelaPerMeter2", "candela per square meter", "candelas per square meter", "cd/m^2", &["nit", "nits"]),
+    unit_info("Meter2PerCandela", "square meter per candela", "square meters per candela", "m^2/cd", &[]),
+
+    absolute_unit_info("DegC", "degree Celsius", "degrees Celsius", "degC", &["°C", "celsius"]),
+    unit_info("DegCDelta", "degree Celsius difference", "degree Celsius differences", "degC", &[]),
+    unit_info("PerDegC", "per degree Celsius", "per degree Celsius", "1/degC", &["1/°C"]),
+    unit_info("DegCPerSecond", "degree Celsius per second", "degrees Celsius per second", "degC/s", &["°C/s"]),
+    unit_info("SecondPerDegC", "second per degree Celsius", "seconds per degree Celsius", "s/degC", &["s/°C"]),
+    unit_info("JouleDegCPerSecond", "joule degree Celsius per second", "joule degrees Celsius per second", "J*degC/s", &[]),
+
+    // conversion
+    unit_info("Foot", "foot", "feet", "ft", &[]),
+    unit_info("Yard", "yard", "yards", "yd", &[]),
+    unit_info("Mile", "mile", "miles", "mi", &[]),
+    unit_info("Kilometer", "kilometer", "kilometers", "km", &["kilometre", "kilometres"]),
+    unit_info("Centimeter", "centimeter", "centimeters", "cm", &["centimetre", "centimetres"]),
+    unit_info("Milimeter", "millimeter", "millimeters", "mm", &["millimetre", "millimetres"]),
+    unit_info("Micrometer", "micrometer", "micrometers", "um", &["µm", "μm", "micrometre", "micrometres", "micron", "microns"]),
+    unit_info("Nanometer", "nanometer", "nanometers", "nm", &["nanometre", "nanometres"]),
+    unit_info("AstronomicalUnit", "astronomical unit", "astronomical units", "au", &["AU"]),
+    unit_info("LightYear", "light year", "light years", "ly", &["lightyear", "lightyears"]),
+    unit_info("Parsec", "parsec", "parsecs", "pc", &[]),
+
+    unit_info("Degree", "degree", "degrees", "deg", &["°"]),
+    unit_info("Gradian", "gradian", "gradians", "gon", &["grad"]),
+    unit_info("ArcMinute", "arc minute", "arc minutes", "arcmin", &["′", "arcminute", "arcminutes"]),
+    unit_info("ArcSecond", "arc second", "arc seconds", "arcsec", &["″", "arcsecond", "arcseconds"]),
+
+    unit_info("Minute", "minute", "minutes", "min", &[]),
+    unit_info("Hour", "hour", "hours", "h", &["hr"]),
+    unit_info("Day", "day", "days", "d", &[]),
+    unit_info("Week", "week", "weeks", "wk", &[]),
+    unit_info("Month", "month", "months", "month", &[]),
+    unit_info("Year", "year", "years", "a", &["yr", "annum"]),
+    unit_info("MilYear", "million years", "million years", "Ma", &["Myr"]),
+    unit_info("Millisecond", "millisecond", "milliseconds", "ms", &[]),
+    unit_info("Microsecond", "microsecond", "microseconds", "us", &["µs", "μs"]),
+    unit_info("Nanosecond", "nanosecond", "nanoseconds", "ns", &[]),
+
+    unit_info("Gram", "gram", "grams", "g", &["gramme", "grammes"]),
+    unit_info("Milligram", "milligram", "milligrams", "mg", &[]),
+    unit_info("Microgram", "microgram", "micrograms", "ug", &["µg", "μg"]),
+    unit_info("Tonne", "tonne", "tonnes", "t", &[]).with_other_names(&["metric ton", "metric tons"]),
+    unit_info("Hectopascal", "hectopascal", "hectopascals", "hPa", &[]),
+    unit_info("Kilopascal", "kilopascal", "kilopascals", "kPa", &[]),
+    unit_info("Megapascal", "megapascal", "megapascals", "MPa", &[]),
+    unit_info("Gigapascal", "gigapascal", "gigapascals", "GPa", &[]),
+    unit_info("Kilojoule", "kilojoule", "kilojoules", "kJ", &[]),
+    unit_info("Megajoule", "megajoule", "megajoules", "MJ", &[]),
+    unit_info("Kilowatt", "kilowatt", "kilowatts", "kW", &[]),
+    unit_info("Megawatt", "megawatt", "megawatts", "MW", &[]),
+
+    absolute_unit_info("Fahrenheit", "degree Fahrenheit", "degrees Fahrenheit", "degF", &["°F", "fahrenheit"]),
+    unit_info("FahrenheitDelta", "degree Fahrenheit difference", "degree Fahrenheit differences", "degF", &[]),
+];
+
+/// All registered units
+pub fn units() -> &'static [UnitInfo] {
+    UNITS
+}
+
+/// Finds a unit by symbol, alias, name, plural or type name: "m", "metre", "meters", "Meter".
+/// Symbols and aliases are case sensitive and are checked first, names are not.
+pub fn lookup(name: &str) -> Option<&'static UnitInfo> {
+    if name.is_empty() {
+        return None;
+    }
+
+    UNITS.iter().find(|unit| unit.matches_symbol(name))
+        .or_else(|| UNITS.iter().find(|unit| unit.matches_name(name)))
+}
+
+/// All units with the given dimension
+pub fn with_dimension(exponents: [i32; BASE_QUANTITIES]) -> impl Iterator<Item = &'static UnitInfo> {
+    UNITS.iter().filter(move |unit| unit.dimension() == exponents)
+}
@@ -0,0 +1,196 @@
+//! simple_units: a simple unit system for Rust
+//!
+//! Written by Willi Kappler, Version 0.1 (2017.02.22)
+//!
+//! Repository: https://github.com/willi-kappler/simple_units
+//!
+//! License: MIT
+//!
+//! Serialization with serde, only available with the feature "serde".
+//!
+//! By default every unit is serialized as a bare number: Meter(1.5) becomes 1.5.
+//! The self describing forms "1.5 m" and {"value": 1.5, "unit": "m"} can be
+//! selected per field with the modules *as_string* and *as_map*:
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Sample {
+//!     #[serde(with = "simple_units::serialization::as_string")]
+//!     length: Meter,
+//!     #[serde(with = "simple_units::serialization::as_map")]
+//!     duration: Second,
+//! }
+//! ```
+//!
+//! Formats that are not human readable (bincode, ...) always use the bare number.
+//!
+//! Deserialization accepts all three forms for every unit. The unit of a self describing
+//! value is checked against the dimension of the target type and the value is converted:
+//! "3 ft" can be read into a Meter, "10 degC" into a Kelvin. Units with a symbol the parser
+//! does not know (like units from the derive macro) accept only their own symbol.
+
+use std::fmt;
+
+use serde::de::{self, Deserializer, MapAccess, Visitor};
+use serde::ser::{SerializeStruct, Serializer};
+use serde::{Deserialize, Serialize};
+
+use ::dimension::Dimension;
+use ::display::{dimension_symbol, DisplayStyle, UnitDisplay};
+use ::parse::{check_same_kind, parse_unit_expression, ParseError, UnitExpression};
+use ::quantity::Quantity;
+
+/// Kind of scale of a unit, decides if the offset of degC and degF is applied
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scale {
+    /// Differences and all other units
+    Relative,
+    /// Absolute temperatures
+    Absolute,
+}
+
+// A value as found in the input, with or without unit
+enum Input {
+    Bare(f64),
+    WithUnit(f64, String),
+}
+
+struct InputVisitor;
+
+impl<'de> Visitor<'de> for InputVisitor {
+    type Value = Input;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a number, a string like \"1.5 m\" or a map with \"value\" and \"unit\"")
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Input, E> {
+        Ok(Input::Bare(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Input, E> {
+        Ok(Input::Bare(value as f64))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Input, E> {
+        Ok(Input::Bare(value as f64))
+    }
+
+    // The unit is only parsed later, it may be a symbol the parser does not know
+    fn visit_str<E: de::Error>(self, input: &str) -> Result<Input, E> {
+        let input = input.trim();
+        let length = number_length(input);
+        let value = input[..length].parse::<f64>()
+            .map_err(|_| E::custom(ParseError::InvalidNumber(input.to_string())))?;
+        Ok(Input::WithUnit(value, input[length..].trim().to_string()))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Input, A::Error> {
+        let mut value = None;
+        let mut unit = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "value" => value = Some(map.next_value::<f64>()?),
+                "unit" => unit = Some(map.next_value::<String>()?),
+                _ => return Err(de::Error::unknown_field(&key, &["value", "unit"])),
+            }
+        }
+
+        let value = value.ok_or_else(|| de::Error::missing_field("value"))?;
+        let unit = unit.ok_or_else(|| de::Error::missing_field("unit"))?;
+        Ok(Input::WithUnit(value, unit))
+    }
+}
+
+// Length of the numeric part of "1.5e3 m", same rule as in *parse_quantity*
+fn number_length(input: &str) -> usize {
+    input.char_indices().map(|(i, c)| i + c.len_utf8())
+        .rev().find(|&end| input[..end].parse::<f64>().is_ok())
+        .unwrap_or(0)
+}
+
+// Converts a value given in the unit *from* into the unit *to*
+fn convert(value: f64, from: &UnitExpression, to: &UnitExpression, scale: Scale) -> Result<f64, ParseError> {
+    if from.exponents != to.exponents {
+        return Err(ParseError::DimensionMismatch { expected: to.exponents, found: from.exponents });
+    }
+
+    match scale {
+        Scale::Relative => Ok(value * from.size / to.size),
+        Scale::Absolute => Ok((value * from.size + from.offset - to.offset) / to.size),
+    }
+}
+
+/// Deserializes the value of a unit with the given ASCII symbol, used by the macros
+#[doc(hidden)]
+pub fn deserialize_value<'de, D: Deserializer<'de>>(deserializer: D, symbol: &str, scale: Scale) -> Result<f64, D::Error> {
+    // Binary formats like bincode can not tell which form follows
+    if !deserializer.is_human_readable() {
+        return f64::deserialize(deserializer);
+    }
+
+    match deserializer.deserialize_any(InputVisitor)? {
+        Input::Bare(value) => Ok(value),
+        Input::WithUnit(value, ref unit) if unit == symbol => Ok(value),
+        Input::WithUnit(value, unit) => {
+            check_same_kind(&unit, symbol).map_err(de::Error::custom)?;
+            let from = parse_unit_expression(&unit).map_err(de::Error::custom)?;
+            let to = parse_unit_expression(symbol).map_err(de::Error::custom)?;
+            convert(value, &from, &to, scale).map_err(de::Error::custom)
+        }
+    }
+}
+
+impl<D: Dimension> Serialize for Quantity<D> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.0)
+    }
+}
+
+impl<'de, D: Dimension> Deserialize<'de> for Quantity<D> {
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Quantity<D>, De::Error> {
+        let symbol = dimension_symbol(D::EXPONENTS, DisplayStyle::Ascii);
+        deserialize_value(deserializer, &symbol, Scale::Relative).map(Quantity::new)
+    }
+}
+
+/// Serializes a unit as a string with its ASCII symbol: "1.5 m"
+pub mod as_string {
+    use super::*;
+
+    /// Use with #[serde(with = "simple_units::serialization::as_string")]
+    pub fn serialize<U: UnitDisplay, S: Serializer>(unit: &U, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            return serializer.serialize_f64(unit.display_value());
+        }
+        serializer.collect_str(&unit.display_as(DisplayStyle::Ascii))
+    }
+
+    /// Accepts all forms, see module *serialization*
+    pub fn deserialize<'de, U: Deserialize<'de>, D: Deserializer<'de>>(deserializer: D) -> Result<U, D::Error> {
+        U::deserialize(deserializer)
+    }
+}
+
+/// Serializes a unit as a map with value and ASCII symbol: {"value": 1.5, "unit": "m"}
+pub mod as_map {
+    use super::*;
+
+    /// Use with #[serde(with = "simple_units::serialization::as_map")]
+    pub fn serialize<U: UnitDisplay, S: Serializer>(unit: &U, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            return serializer.serialize_f64(unit.display_value());
+        }
+        let mut map = serializer.serialize_struct("Unit", 2)?;
+        map.serialize_field("value", &unit.display_value())?;
+        map.serialize_field("unit", &U::display_symbol(DisplayStyle::Ascii))?;
+        map.end()
+    }
+
+    /// Accepts all forms, see module *serialization*
+    pub fn deserialize<'de, U: Deserialize<'de>, D: Deserializer<'de>>(deserializer: D) -> Result<U, D::Error> {
+        U::deserialize(deserializer)
+    }
+}
@@ -7,115 +7,218 @@
 //! License: MIT
 //!
 
-use std::ops::Add;
-use std::ops::Sub;
-use std::ops::Mul;
-use std::ops::Div;
-use std::cmp::PartialEq;
+use ::dimension::Dimensionless;
+
+// Units in coherent SI are aliases of the generic Quantity type,
+// so multiplication and division work for any combination of them.
+// The exponents are given in the order:
+// length, mass, time, temperature, amount, current, luminosity
+
+init_quantity!(Unitless, Dimensionless);
+
+// Angles are dimensionless in SI, but they are separate units,
+// so that an angle in degrees can not be used as radian by mistake
+init_unit!(Radian, "rad", "rad", "\\radian");
+init_unit!(Steradian, "sr", "sr", "\\steradian");
+
+convert_unit!(Unitless, Radian, size = 1.0);
+convert_unit!(Unitless, Steradian, size = 1.0);
+
+impl Radian {
+    /// Sine of the angle
+    pub fn sin(self) -> Unitless {
+        Unitless(self.0.sin())
+    }
+
+    /// Cosine of the angle
+    pub fn cos(self) -> Unitless {
+        Unitless(self.0.cos())
+    }
+
+    /// Tangent of the angle
+    pub fn tan(self) -> Unitless {
+        Unitless(self.0.tan())
+    }
+
+    /// Arcsine, the result is in [-pi/2, pi/2]
+    pub fn asin(value: Unitless) -> Radian {
+        Radian(value.0.asin())
+    }
+
+    /// Arccosine, the result is in [0, pi]
+    pub fn acos(value: Unitless) -> Radian {
+        Radian(value.0.acos())
+    }
+
+    /// Arctangent, the result is in [-pi/2, pi/2]
+    pub fn atan(value: Unitless) -> Radian {
+        Radian(value.0.atan())
+    }
+}
+
+init_quantity_and_inverse!(Meter, PerMeter, P1, Z0, Z0, Z0, Z0, Z0, Z0);
+init_quantity_and_inverse!(Meter2, PerMeter2, P2, Z0, Z0, Z0, Z0, Z0, Z0);
+init_quantity_and_inverse!(Meter3, PerMeter3, P3, Z0, Z0, Z0, Z0, Z0, Z0);
+
+init_quantity_and_inverse!(Second, PerSecond, Z0, Z0, P1, Z0, Z0, Z0, Z0);
+init_quantity_and_inverse!(Second2, PerSecond2, Z0, Z0, P2, Z0, Z0, Z0, Z0);
+init_quantity_and_inverse!(Second3, PerSecond3, Z0, Z0, P3, Z0, Z0, Z0, Z0);
+
+init_quantity_and_inverse!(MeterPerSecond, SecondPerMeter, P1, Z0, N1, Z0, Z0, Z0, Z0);
+init_quantity_and_inverse!(MeterPerSecond2, Second2PerMeter, P1, Z0, N2, Z0, Z0, Z0, Z0);
+init_quantity_and_inverse!(Meter2PerSecond, SecondPerMeter2, P2, Z0, N1, Z0, Z0, Z0, Z0);
+init_quantity_and_inverse!(Meter2PerSecond2, Second2PerMeter2, P2, Z0, N2, Z0, Z0, Z0, Z0);
+init_quantity_and_inverse!(MeterSecond, PerMeterSecond, P1, Z0, P1, Z0, Z0, Z0, Z0);
+init_quantity_and_inverse!(Meter2Second, PerMeter2Second, P2, Z0, P1, Z0, Z0, Z0, Z0);
+init_quantity_and_inverse!(MeterSecond2, PerMeterSecond2, P1, Z0, P2, Z0, Z0, Z0, Z0);
+init_quantity_and_inverse!(Meter2Second2, PerMeter2Second2, P2, Z0, P2, Z0, Z0, Z0, Z0);
+
+
+init_quantity_and_inverse!(Kilogram, PerKilogram, Z0, P1, Z0, Z0, Z0, Z0, Z0);
 
-init_unit_and_inverse!(Meter, PerMeter);
-init_unit_and_inverse!(Meter2, PerMeter2);
-init_unit_and_inverse!(Meter3, PerMeter3);
-power3_unit!(Meter, Meter2, Meter3, PerMeter, PerMeter2, PerMeter3);
+ // momentum, impulse
+init_quantity_and_inverse!(KilogramMeterPerSecond, SecondPerKilogramMeter, P1, P1, N1, Z0, Z0, Z0, Z0);
 
-init_unit_and_inverse!(Second, PerSecond);
-init_unit_and_inverse!(Second2, PerSecond2);
-init_unit_and_inverse!(Second3, PerSecond3);
-power3_unit!(Second, Second2, Second3, PerSecond, PerSecond2, PerSecond3);
+init_quantity_and_inverse!(Meter3PerKilogramSecond2, KilogramSecond2PerMeter3, P3, N1, N2, Z0, Z0, Z0, Z0);
 
-init_unit_and_inverse!(MeterPerSecond, SecondPerMeter);
-init_unit_and_inverse!(MeterPerSecond2, Second2PerMeter);
-init_unit_and_inverse!(Meter2PerSecond, SecondPerMeter2);
-init_unit_and_inverse!(Meter2PerSecond2, Second2PerMeter2);
-init_unit_and_inverse!(MeterSecond, PerMeterSecond);
-init_unit_and_inverse!(Meter2Second, PerMeter2Second);
-init_unit_and_inverse!(MeterSecond2, PerMeterSecond2);
-init_unit_and_inverse!(Meter2Second2, PerMeter2Second2);
+init_quantity_and_inverse!(Newton, PerNewton, P1, P1, N2, Z0, Z0, Z0, Z0);
 
-combine_unit!(Meter, PerMeter, Second, PerSecond, MeterSecond, MeterPerSecond, SecondPerMeter, PerMeterSecond);
-combine_unit!(Meter2, PerMeter2, Second, PerSecond, Meter2Second, Meter2PerSecond, SecondPerMeter2, PerMeter2Second);
-combine_unit!(Meter, PerMeter, Second2, PerSecond2, MeterSecond2, MeterPerSecond2, Second2PerMeter, PerMeterSecond2);
-combine_unit!(Meter2, PerMeter2, Second2, PerSecond2, Meter2Second2, Meter2PerSecond2, Second2PerMeter2, PerMeter2Second2);
+init_quantity_and_inverse!(Pascal, PerPascal, N1, P1, N2, Z0, Z0, Z0, Z0);
 
-mul_div_unit!(PerSecond2, Meter2Second, Meter2PerSecond);
+init_quantity_and_inverse!(Joule, PerJoule, P2, P1, N2, Z0, Z0, Z0, Z0);
 
+init_quantity_and_inverse!(Watt, PerWatt, P2, P1, N3, Z0, Z0, Z0, Z0);
 
-init_unit_and_inverse!(Kilogram, PerKilogram);
+init_quantity_and_inverse!(JouleSecond, PerJouleSecond, P2, P1, N1, Z0, Z0, Z0, Z0);
 
- // momentum, impulse
-init_unit_and_inverse!(KilogramMeterPerSecond, SecondPerKilogramMeter);
+// Temperature difference, the coherent SI quantity used in derived units
+init_quantity_and_inverse!(KelvinDelta, PerKelvin, Z0, Z0, Z0, P1, Z0, Z0, Z0);
 
-mul_div_unit!(Kilogram, MeterPerSecond, KilogramMeterPerSecond);
+init_quantity_and_inverse!(Kelvin2, PerKelvin2, Z0, Z0, Z0, P2, Z0, Z0, Z0);
 
-init_unit_and_inverse!(Newton, PerNewton);
+init_quantity_and_inverse!(KelvinPerSecond, SecondPerKelvin, Z0, Z0, N1, P1, Z0, Z0, Z0);
 
-mul_div_unit!(Kilogram, MeterPerSecond2, Newton);
-mul_div_unit!(Newton, Second, KilogramMeterPerSecond);
+init_quantity!(JouleKelvinPerSecond, P2, P1, N3, P1, Z0, Z0, Z0);
 
-init_unit_and_inverse!(Pascal, PerPascal);
+init_quantity_and_inverse!(Mol, PerMol, Z0, Z0, Z0, Z0, P1, Z0, Z0);
 
-mul_div_unit!(Pascal, Meter2, Newton);
+init_quantity_and_inverse!(JoulePerKelvin, KelvinPerJoule, P2, P1, N2, N1, Z0, Z0, Z0);
 
-init_unit_and_inverse!(Joule, PerJoule);
+init_quantity_and_inverse!(JoulePerMol, MolPerJoule, P2, P1, N2, Z0, N1, Z0, Z0);
 
-mul_div_unit!(Joule, Meter, Newton);
+init_quantity_and_inverse!(JoulePerKelvinMol, KelvinMolPerJoule, P2, P1, N2, N1, N1, Z0, Z0);
 
-init_unit_and_inverse!(Watt, PerWatt);
+init_quantity_and_inverse!(JouleKelvinPerMol, MolPerJouleKelvin, P2, P1, N2, P1, N1, Z0, Z0);
 
-mul_div_unit!(Watt, Second, Joule);
+init_quantity_and_inverse!(SecondPerMol, MolPerSecond, Z0, Z0, P1, Z0, N1, Z0, Z0);
 
-init_unit_and_inverse!(DegC, PerDegC);
+init_quantity_and_inverse!(JouleKelvinPerMolSecond, MolSecondPerJouleKelvin, P2, P1, N3, P1, N1, Z0, Z0);
 
-init_unit_and_inverse!(Kelvin, PerKelvin);
+init_quantity_and_inverse!(Ampere, PerAmpere, Z0, Z0, Z0, Z0, Z0, P1, Z0);
 
-init_unit_and_inverse!(Kelvin2, PerKelvin2);
+init_quantity_and_inverse!(Ampere2, PerAmpere2, Z0, Z0, Z0, Z0, Z0, P2, Z0);
 
-mul_div_unit!(Kelvin, Kelvin2);
+// magnetic field strength
+init_quantity_and_inverse!(AmperePerMeter, MeterPerAmpere, N1, Z0, Z0, Z0, Z0, P1, Z0);
 
-init_unit_and_inverse!(KelvinPerSecond, SecondPerKelvin);
+// current density
+init_quantity_and_inverse!(AmperePerMeter2, Meter2PerAmpere, N2, Z0, Z0, Z0, Z0, P1, Z0);
 
-mul_div_unit!(KelvinPerSecond, Second, Kelvin);
+init_quantity_and_inverse!(AmperePerSecond, SecondPerAmpere, Z0, Z0, N1, Z0, Z0, P1, Z0);
 
-init_unit_and_inverse!(DegCPerSecond, SecondPerDegC);
+// Electromagnetic units
 
-mul_div_unit!(DegCPerSecond, Second, DegC);
+init_quantity_and_inverse!(Coulomb, PerCoulomb, Z0, Z0, P1, Z0, Z0, P1, Z0);
 
-init_unit!(JouleDegCPerSecond);
+init_quantity_and_inverse!(Volt, PerVolt, P2, P1, N3, Z0, Z0, N1, Z0);
 
-mul_div_unit!(DegCPerSecond, Joule, JouleDegCPerSecond);
+// electric field strength
+init_quantity_and_inverse!(VoltPerMeter, MeterPerVolt, P1, P1, N3, Z0, Z0, N1, Z0);
 
-init_unit!(JouleKelvinPerSecond);
+init_quantity_and_inverse!(Ohm, Siemens, P2, P1, N3, Z0, Z0, N2, Z0);
 
-mul_div_unit!(KelvinPerSecond, Joule, JouleKelvinPerSecond);
+init_quantity_and_inverse!(Farad, PerFarad, N2, N1, P4, Z0, Z0, P2, Z0);
 
-init_unit_and_inverse!(Mol, PerMol);
+init_quantity_and_inverse!(Henry, PerHenry, P2, P1, N2, Z0, Z0, N2, Z0);
 
-init_unit_and_inverse!(JoulePerKelvin, KelvinPerJoule);
+init_quantity_and_inverse!(Weber, PerWeber, P2, P1, N2, Z0, Z0, N1, Z0);
 
-init_unit_and_inverse!(JoulePerMol, MolPerJoule);
+init_quantity_and_inverse!(Tesla, PerTesla, Z0, P1, N2, Z0, Z0, N1, Z0);
 
-init_unit_and_inverse!(JoulePerKelvinMol, KelvinMolPerJoule);
+// Units with the dimension of PerSecond, JoulePerKilogram, MolPerSecond, Joule or Meter2
+// that describe different kinds of quantities, see module *kind*
 
-init_unit_and_inverse!(JouleKelvinPerMol, MolPerJouleKelvin);
+init_quantity_and_inverse!(JoulePerKilogram, KilogramPerJoule, P2, Z0, N2, Z0, Z0, Z0, Z0);
 
-mul_div_unit!(JoulePerKelvinMol, Kelvin2, JouleKelvinPerMol);
+init_kind!(Hertz, PerSecond, "Hz", "Hz", "\\hertz");
+init_kind!(Becquerel, PerSecond, "Bq", "Bq", "\\becquerel");
+init_kind!(RadianPerSecond, PerSecond, "rad/s", "rad·s⁻¹", "\\radian\\per\\second");
+init_kind!(Gray, JoulePerKilogram, "Gy", "Gy", "\\gray");
+init_kind!(Sievert, JoulePerKilogram, "Sv", "Sv", "\\sievert");
+init_kind!(Katal, MolPerSecond, "kat", "kat", "\\katal");
 
-init_unit_and_inverse!(SecondPerMol, MolPerSecond);
+// torque
+init_kind!(NewtonMeter, Joule, "N*m", "N·m", "\\newton\\metre");
 
-init_unit_and_inverse!(JouleKelvinPerMolSecond, MolSecondPerJouleKelvin);
+// cross section
+init_kind!(Barn, Meter2, size = 1.0e-28, "b", "b", "\\barn");
 
-mul_div_unit!(SecondPerMol, JouleKelvinPerSecond, JouleKelvinPerMol);
+mul_div_unit!(RadianPerSecond, Second, Radian);
 
-mul_div_unit!(Mol, SecondPerMol, Second);
+// There is no From between Hertz and RadianPerSecond: via PerSecond one rad/s is 1/s (SI),
+// but one turn per second is 2π rad/s. The factor is applied only by these methods
+impl Hertz {
+    /// Angular frequency of a periodic process: 2π rad/s per Hz.
+    /// There is no *From*, the factor must be chosen explicitly:
+    ///
+    /// ```compile_fail
+    /// # use simple_units::si_units::*;
+    /// let omega = RadianPerSecond::from(Hertz(1.0));
+    /// ```
+    pub fn to_angular(self) -> RadianPerSecond {
+        RadianPerSecond(self.0 * 2.0 * ::std::f64::consts::PI)
+    }
+}
 
-mul_div_unit!(Mol, JoulePerKelvinMol, JoulePerKelvin);
+impl RadianPerSecond {
+    /// Frequency of a periodic process: one Hz per 2π rad/s
+    pub fn to_frequency(self) -> Hertz {
+        Hertz(self.0 / (2.0 * ::std::f64::consts::PI))
+    }
+}
 
-mul_div_unit!(Kelvin, JoulePerKelvin, Joule);
+init_quantity_and_inverse!(Candela, PerCandela, Z0, Z0, Z0, Z0, Z0, Z0, P1);
 
-mul_div_unit!(JoulePerMol, KelvinPerSecond, JouleKelvinPerMolSecond);
+// luminance
+init_quantity_and_inverse!(CandelaPerMeter2, Meter2PerCandela, N2, Z0, Z0, Z0, Z0, Z0, P1);
+
+// Absolute temperatures are points on a scale: the difference of two absolute
+// temperatures is a temperature difference, but they can not be added.
+
+init_absolute_unit!(Kelvin, KelvinDelta, "K", "K", "\\kelvin");
+
+mul_div_unit!(Kelvin, Kelvin2);
 
-mul_div_unit!(Kelvin,  JoulePerKelvinMol, JoulePerMol);
+// The absolute temperature in Kelvin is the difference to absolute zero
+convert_unit!(Kelvin, KelvinDelta, |value| {value}, |value| {value});
 
-mul_div_unit!(Second, JouleKelvinPerMolSecond, JouleKelvinPerMol);
+// Degree Celsius is not a coherent SI unit (it has an offset),
+// so it is a separate newtype that must be converted explicitly.
+
+init_unit!(DegCDelta, "degC", "°C", "\\degreeCelsius");
+init_unit!(PerDegC, "1/degC", "°C⁻¹", "\\per\\degreeCelsius");
+inverse_unit!(DegCDelta, PerDegC);
+
+init_absolute_unit!(DegC, DegCDelta, "degC", "°C", "\\degreeCelsius");
+
+init_unit!(DegCPerSecond, "degC/s", "°C·s⁻¹", "\\degreeCelsius\\per\\second");
+init_unit!(SecondPerDegC, "s/degC", "s·°C⁻¹", "\\second\\per\\degreeCelsius");
+inverse_unit!(DegCPerSecond, SecondPerDegC);
+
+mul_div_unit!(DegCPerSecond, Second, DegCDelta);
+
+init_unit!(JouleDegCPerSecond, "J*degC/s", "J·°C·s⁻¹", "\\joule\\degreeCelsius\\per\\second");
+
+mul_div_unit!(DegCPerSecond, Joule, JouleDegCPerSecond);
 
 // TODO: add more units...
@@ -0,0 +1,56 @@
+extern crate simple_units;
+
+use simple_units::si_units::*;
+use simple_units::conversion::*;
+use simple_units::parse::ParseError;
+
+fn assert_close(value: f64, expected: f64) {
+    assert!((value - expected).abs() <= 1.0e-12 * expected.abs(), "{} != {}", value, expected);
+}
+
+#[test]
+fn test_angle() {
+    assert_close(Radian::from(Degree(180.0)).0, ::std::f64::consts::PI);
+    assert_close(Degree::from(Radian(::std::f64::consts::PI)).0, 180.0);
+    assert_close(Degree::from(Radian::from(Gradian(100.0))).0, 90.0);
+    assert_close(Radian::from(ArcMinute(60.0)).0, Radian::from(Degree(1.0)).0);
+    assert_close(Radian::from(ArcSecond(3600.0)).0, Radian::from(Degree(1.0)).0);
+    assert_eq!(Radian::from(Meter(2.0) / Meter(1.0)), Radian(2.0));
+    assert_eq!(Unitless::from(Steradian(4.0)), Unitless(4.0));
+}
+
+#[test]
+fn test_trigonometry() {
+    assert_close(Degree(30.0).sin().0, 0.5);
+    assert_close(Degree(60.0).cos().0, 0.5);
+    assert_close(Gradian(50.0).tan().0, 1.0);
+    assert_close(Radian(0.0).cos().0, 1.0);
+    assert_close(Degree::from(Radian::asin(Unitless(0.5))).0, 30.0);
+    assert_close(Degree::from(Radian::atan(Unitless(1.0))).0, 45.0);
+    assert_close(Degree::from(Meter(1.0).atan2(Meter(1.0))).0, 45.0);
+    assert_close(Degree::from(Foot(-1.0).atan2(Foot(0.0))).0, -90.0);
+}
+
+#[test]
+fn test_parse_angle() {
+    assert_close("180 deg".parse::<Radian>().unwrap().0, ::std::f64::consts::PI);
+    assert_close("90°".parse::<Radian>().unwrap().0, ::std::f64::consts::PI / 2.0);
+    assert_close("1 rad".parse::<Degree>().unwrap().0, 180.0 / ::std::f64::consts::PI);
+    assert_close("30 arcmin".parse::<Degree>().unwrap().0, 0.5);
+    assert_close("100 gon".parse::<Degree>().unwrap().0, 90.0);
+    assert_eq!(format!("{}", Degree(90.0)), "90 °");
+    assert_eq!(format!("{}", Steradian(2.0)), "2 sr");
+    assert_eq!("2 sr".parse::<Steradian>(), Ok(Steradian(2.0)));
+    assert_close("2 mrad".parse::<Radian>().unwrap().0, 2.0e-3);
+    assert_close("2".parse::<Radian>().unwrap().0, 2.0);
+}
+
+#[test]
+fn test_parse_angle_kinds() {
+    assert_eq!("90 deg".parse::<Steradian>(), Err(ParseError::UnknownUnit("deg".to_string())));
+    assert_eq!("1 rad".parse::<Steradian>(), Err(ParseError::UnknownUnit("rad".to_string())));
+    assert_eq!("5 sr".parse::<Radian>(), Err(ParseError::UnknownUnit("sr".to_string())));
+    assert_eq!("5 msr".parse::<Degree>(), Err(ParseError::UnknownUnit("msr".to_string())));
+    assert_eq!("5 sr".parse::<Gradian>(), Err(ParseError::UnknownUnit("sr".to_string())));
+    assert!("1 m".parse::<Radian>().is_err());
+}
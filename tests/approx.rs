@@ -0,0 +1,70 @@
+#[macro_use] extern crate simple_units;
+
+use simple_units::si_units::*;
+use simple_units::conversion::*;
+use simple_units::approx::{ApproxEq, Tolerance, approx_eq_f64};
+
+#[test]
+fn test_absolute() {
+    assert!(Meter(1.0).approx_eq_abs(Meter(1.0005), 1.0e-3));
+    assert!(!Meter(1.0).approx_eq_abs(Meter(1.002), 1.0e-3));
+    assert!(Meter(0.0).approx_eq_abs(Meter(-1.0e-20), 1.0e-15));
+    assert!(Foot(3.0).approx_eq(Foot(3.1), Tolerance::Absolute(0.2)));
+}
+
+#[test]
+fn test_relative() {
+    assert!(Joule(1.0e20).approx_eq_rel(Joule(1.0e20 + 1.0e7), 1.0e-12));
+    assert!(!Joule(1.0e-20).approx_eq_rel(Joule(1.1e-20), 1.0e-12));
+    assert!(Joule(0.0).approx_eq_rel(Joule(0.0), 1.0e-12));
+    assert!(!Joule(0.0).approx_eq_rel(Joule(1.0e-300), 1.0e-12));
+    assert!((Meter(1.0) / 3.0 * 3.0).approx_eq_rel(Meter(1.0), 1.0e-15));
+}
+
+#[test]
+fn test_ulps() {
+    assert!(!(Second(0.1) + Second(0.2) == Second(0.3)));
+    assert!((Second(0.1) + Second(0.2)).approx_eq_ulps(Second(0.3), 1));
+    assert!(!Second(1.0).approx_eq_ulps(Second(1.0 + 4.0 * f64::EPSILON), 3));
+    assert!(Second(1.0).approx_eq_ulps(Second(1.0 + 4.0 * f64::EPSILON), 4));
+
+    // Across zero
+    assert!(approx_eq_f64(0.0, -0.0, Tolerance::Ulps(0)));
+    assert!(approx_eq_f64(f64::MIN_POSITIVE * f64::EPSILON, -f64::MIN_POSITIVE * f64::EPSILON, Tolerance::Ulps(2)));
+}
+
+#[test]
+fn test_special_values() {
+    assert!(!Meter(f64::NAN).approx_eq_abs(Meter(f64::NAN), 1.0));
+    assert!(Meter(f64::INFINITY).approx_eq_rel(Meter(f64::INFINITY), 0.0));
+    assert!(!Meter(f64::INFINITY).approx_eq_abs(Meter(f64::MAX), f64::MAX));
+    assert!(!Meter(f64::INFINITY).approx_eq_ulps(Meter(f64::NEG_INFINITY), u64::MAX));
+}
+
+#[test]
+fn test_all_units() {
+    assert!(DegC(20.0).approx_eq_rel(DegC::from(Kelvin(293.15)), 1.0e-12));
+    assert!(Hour(1.5).approx_eq_ulps(Hour::from(Minute(90.0)), 2));
+    assert!(Degree(180.0).approx_eq_rel(Radian(std::f64::consts::PI).to(), 1.0e-15));
+}
+
+#[test]
+fn test_assert_unit_eq() {
+    assert_unit_eq!(Kelvin::from(DegC(20.0)), Kelvin(293.15), rel = 1.0e-12);
+    assert_unit_eq!(Mile(1.0).to::<Foot>(), Foot(5280.0), abs = 1.0e-9);
+    assert_unit_eq!(Second(0.1) + Second(0.2), Second(0.3), ulps = 1);
+    assert_unit_eq!(Meter(1.0), Meter(1.0), Tolerance::Ulps(0));
+    assert_unit_ne!(Meter(1.0), Meter(1.1), rel = 1.0e-3);
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: `left ≈ right` (rel = 1e-12)\n  left: 1 m\n right: 1.001 m")]
+fn test_assert_unit_eq_message() {
+    assert_unit_eq!(Meter(1.0), Meter(1.001), rel = 1.0e-12);
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: `left ≉ right` (abs = 1e-2)\n  left: 1 ft\n right: 1.001 ft")]
+fn test_assert_unit_ne_message() {
+    assert_unit_ne!(Foot(1.0), Foot(1.001), abs = 0.01);
+}
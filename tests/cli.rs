@@ -0,0 +1,58 @@
+use std::process::{Command, Output};
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_simple_units")).args(args).output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn test_convert() {
+    let output = run(&["convert", "300 degC", "K"]);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "300 degC = 573.15 K\ndimension: Θ (K, degC, degF)\n");
+
+    let output = run(&["convert", "500 um", "m"]);
+    assert!(stdout(&output).starts_with("500 um = 0.0005 m\ndimension: L (m, ft,"));
+
+    // Names of registered units are accepted as target
+    let output = run(&["convert", "90 min", "hours"]);
+    assert!(stdout(&output).starts_with("90 min = 1.5 hours\n"));
+}
+
+#[test]
+fn test_convert_incompatible() {
+    let output = run(&["convert", "3 s", "m"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stderr(&output), "error: incompatible units: '3 s' has dimension T, 'm' has dimension L\n");
+
+    let output = run(&["convert", "3 furlong", "m"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stderr(&output), "error: 3 furlong: unknown unit: 'furlong'\n");
+}
+
+#[test]
+fn test_check() {
+    let output = run(&["check", "J/(K mol) * K^2 / (J/mol * K/s)"]);
+    assert!(output.status.success());
+    assert!(stdout(&output).starts_with("J/(K mol) * K^2 / (J/mol * K/s) = 1 s\ndimension: T (s, min,"));
+
+    let output = run(&["check", "m/m"]);
+    assert!(stdout(&output).starts_with("m/m = 1\ndimension: 1"));
+
+    let output = run(&["check", "m^"]);
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn test_usage() {
+    let output = run(&["convert", "3 m"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stderr(&output).starts_with("usage:"));
+}
@@ -0,0 +1,74 @@
+extern crate simple_units;
+
+use simple_units::si_units::*;
+use simple_units::constants::*;
+
+fn assert_close(value: f64, expected: f64) {
+    assert!((value - expected).abs() <= 1.0e-12 * expected.abs(), "{} != {}", value, expected);
+}
+
+#[test]
+fn test_typed_constants() {
+    let _: MeterPerSecond = SPEED_OF_LIGHT.value;
+    let _: JouleSecond = PLANCK_CONSTANT.value;
+    let _: Coulomb = ELEMENTARY_CHARGE.value;
+    let _: JoulePerKelvin = BOLTZMANN_CONSTANT.value;
+    let _: PerMol = AVOGADRO_CONSTANT.value;
+    let _: JoulePerKelvinMol = GAS_CONSTANT.value;
+    let _: Meter3PerKilogramSecond2 = GRAVITATIONAL_CONSTANT.value;
+    let _: MeterPerSecond2 = STANDARD_GRAVITY.value;
+}
+
+#[test]
+fn test_values() {
+    assert_eq!(SPEED_OF_LIGHT.value, MeterPerSecond(299792458.0));
+    assert_eq!(STANDARD_GRAVITY.value, MeterPerSecond2(9.80665));
+
+    // R = N_A k
+    let gas_constant: JoulePerKelvinMol = AVOGADRO_CONSTANT.value * BOLTZMANN_CONSTANT.value;
+    assert_close(gas_constant.0, GAS_CONSTANT.value.0);
+
+    // Weight of 1 kg
+    let weight: Newton = Kilogram(1.0) * STANDARD_GRAVITY.value;
+    assert_eq!(weight, Newton(9.80665));
+
+    // Acceleration at the surface of the earth
+    let g: MeterPerSecond2 = GRAVITATIONAL_CONSTANT.value * Kilogram(5.9722e24) / (Meter(6.371e6) * Meter(6.371e6));
+    assert!((g.0 - 9.82).abs() < 0.01);
+}
+
+#[test]
+fn test_uncertainty() {
+    assert!(PLANCK_CONSTANT.is_exact());
+    assert!(GAS_CONSTANT.is_exact());
+    assert!(!GRAVITATIONAL_CONSTANT.is_exact());
+    assert_eq!(GRAVITATIONAL_CONSTANT.uncertainty, Meter3PerKilogramSecond2(1.5e-15));
+    assert_close(GRAVITATIONAL_CONSTANT.relative_uncertainty(), 1.5e-15 / 6.67430e-11);
+    assert_eq!(GAS_CONSTANT.relative_uncertainty(), 0.0);
+}
+
+#[test]
+fn test_release() {
+    assert_eq!(CODATA_RELEASE, "CODATA 2022");
+    assert_eq!(GAS_CONSTANT.release, "CODATA 2022");
+    assert_eq!(GAS_CONSTANT.name, "molar gas constant");
+}
+
+fn gas_constant<C: ConstantSet>() -> JoulePerKelvinMol {
+    C::GAS_CONSTANT.value
+}
+
+#[test]
+fn test_constant_sets() {
+    assert_eq!(gas_constant::<Codata2006>(), JoulePerKelvinMol(8.314472));
+    assert_eq!(gas_constant::<Codata2010>(), JoulePerKelvinMol(8.3144621));
+    assert_eq!(gas_constant::<Codata2014>(), JoulePerKelvinMol(8.3144598));
+    assert_eq!(gas_constant::<Codata2018>(), GAS_CONSTANT.value);
+
+    assert_eq!(Codata2010::RELEASE, "CODATA 2010");
+    assert_eq!(Codata2010::BOLTZMANN_CONSTANT.release, "CODATA 2010");
+    assert_eq!(Codata2014::PLANCK_CONSTANT.uncertainty, JouleSecond(0.000000081e-34));
+    assert!(!Codata2014::AVOGADRO_CONSTANT.is_exact());
+    assert!(Codata2018::AVOGADRO_CONSTANT.is_exact());
+    assert_eq!(Codata2022::GRAVITATIONAL_CONSTANT, GRAVITATIONAL_CONSTANT);
+}
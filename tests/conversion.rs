@@ -1,50 +1,59 @@
-extern crate simple_units;
+#[macro_use] extern crate simple_units;
 
 use simple_units::si_units::*;
 use simple_units::conversion::*;
 
 #[test]
 fn test_meter_to_foot() {
-    assert_eq!((Foot::from(Meter(1.0))), Foot(3.28084));
+    assert_eq!((Foot::from(Meter(0.3048))), Foot(1.0));
 
-    let value: Foot = Meter(1.0).into();
-    assert_eq!(value, Foot(3.28084));
+    let value: Foot = Meter(0.3048).into();
+    assert_eq!(value, Foot(1.0));
 
     let value = Foot(1.0) + Meter(1.0).into();
-    assert_eq!(value, Foot(4.2808399999999995));
+    assert_unit_eq!(value, Foot(4.280839895013123), rel = 1.0e-12);
 }
 
 #[test]
 fn test_foot_to_meter() {
-    assert_eq!((Meter::from(Foot(3.28084))), Meter(1.0));
+    assert_eq!((Meter::from(Foot(1.0))), Meter(0.3048));
 
-    let value: Meter = Foot(3.28084).into();
-    assert_eq!(value, Meter(1.0));
+    let value: Meter = Foot(1.0).into();
+    assert_eq!(value, Meter(0.3048));
 
-    let value = Meter(1.0) + Foot(3.28084).into();
-    assert_eq!(value, Meter(2.0));
+    let value = Meter(1.0) + Foot(1.0).into();
+    assert_eq!(value, Meter(1.3048));
 }
 
 #[test]
 fn test_meter_to_yard() {
-    assert_eq!((Yard::from(Meter(1.0))), Yard(1.09361));
+    assert_eq!((Yard::from(Meter(0.9144))), Yard(1.0));
 
-    let value: Yard = Meter(1.0).into();
-    assert_eq!(value, Yard(1.09361));
+    let value: Yard = Meter(0.9144).into();
+    assert_eq!(value, Yard(1.0));
 
     let value = Yard(1.0) + Meter(1.0).into();
-    assert_eq!(value, Yard(2.09361));
+    assert_eq!(value, Yard(2.093613298337708));
 }
 
 #[test]
 fn test_yard_to_meter() {
-    assert_eq!((Meter::from(Yard(1.09361))), Meter(1.0));
+    assert_eq!((Meter::from(Yard(1.0))), Meter(0.9144));
 
-    let value: Meter = Yard(1.09361).into();
-    assert_eq!(value, Meter(1.0));
+    let value: Meter = Yard(1.0).into();
+    assert_eq!(value, Meter(0.9144));
 
-    let value = Meter(1.0) + Yard(1.09361).into();
-    assert_eq!(value, Meter(2.0));
+    let value = Meter(1.0) + Yard(1.0).into();
+    assert_eq!(value, Meter(1.9144));
+}
+
+#[test]
+fn test_second_to_minute() {
+    assert_eq!(Minute::from(Second(60.0)), Minute(1.0));
+    assert_eq!(Hour::from(Second(7200.0)), Hour(2.0));
+    assert_eq!(Day::from(Second::from(Hour(48.0))), Day(2.0));
+    assert_eq!(Second::from(Year(1.0)), Second(31557600.0));
+    assert_eq!(Second::from(MilYear(1.0)), Second(31557600.0e6));
 }
 
 #[test]
@@ -54,7 +63,7 @@ fn test_degc_to_kelvin() {
     let value: Kelvin = DegC(0.0).into();
     assert_eq!(value, Kelvin(273.15));
 
-    let value = Kelvin(5.5) + DegC(0.0).into();
+    let value = Kelvin::from(DegC(0.0)) + KelvinDelta(5.5);
     assert_eq!(value, Kelvin(278.65));
 }
 
@@ -65,7 +74,7 @@ fn test_kelvin_to_degc() {
     let value: DegC = Kelvin(0.0).into();
     assert_eq!(value, DegC(-273.15));
 
-    let value = DegC(5.5) + Kelvin(0.0).into();
+    let value = DegC::from(Kelvin(0.0)) + DegCDelta(5.5);
     assert_eq!(value, DegC(-267.65));
 }
 
@@ -76,6 +85,99 @@ fn test_degc_to_fahrenheit() {
     let value: DegC = Fahrenheit(100.0).into();
     assert_eq!(value, DegC(37.77777777777778));
 
-    let value = DegC(5.5) + Fahrenheit(200.0).into();
+    let value = DegC::from(Fahrenheit(200.0)) + DegCDelta(5.5);
     assert_eq!(value, DegC(98.83333333333333));
 }
+
+#[test]
+fn test_temperature_difference() {
+    assert_eq!(DegC(300.0) - DegC(200.0), DegCDelta(100.0));
+    assert_eq!(Kelvin(300.0) - Kelvin(200.0), KelvinDelta(100.0));
+    assert_eq!(Fahrenheit(50.0) - Fahrenheit(32.0), FahrenheitDelta(18.0));
+
+    assert_eq!(DegC(20.0) + DegCDelta(5.0), DegC(25.0));
+    assert_eq!(DegCDelta(5.0) + DegC(20.0), DegC(25.0));
+    assert_eq!(DegC(20.0) - DegCDelta(5.0), DegC(15.0));
+}
+
+#[test]
+fn test_delta_conversion() {
+    // No offset for differences
+    assert_eq!(KelvinDelta::from(DegC(300.0) - DegC(200.0)), KelvinDelta(100.0));
+    assert_eq!(DegCDelta::from(KelvinDelta(10.0)), DegCDelta(10.0));
+    assert_eq!(FahrenheitDelta::from(DegCDelta(10.0)), FahrenheitDelta(18.0));
+    assert_eq!(FahrenheitDelta::from(KelvinDelta(10.0)), FahrenheitDelta(18.0));
+    assert_eq!(DegCDelta::from(FahrenheitDelta(18.0)), DegCDelta(10.0));
+    assert_eq!(DegCPerSecond::from(KelvinPerSecond(2.0)), DegCPerSecond(2.0));
+}
+
+#[test]
+fn test_absolute_kelvin() {
+    assert_eq!(KelvinDelta::from(Kelvin(300.0)), KelvinDelta(300.0));
+    assert_eq!(Kelvin::from(KelvinDelta(300.0)), Kelvin(300.0));
+    assert_eq!(Kelvin(3.0) * Kelvin(2.0), Kelvin2(6.0));
+}
+
+fn assert_close(value: f64, expected: f64) {
+    assert!((value - expected).abs() <= 1.0e-12 * expected.abs(), "{} != {}", value, expected);
+}
+
+macro_rules! round_trip {
+    ($base:ident, $unit:ident, $size:expr) => {
+        assert_close($base::from($unit(1.0)).0, $size);
+        assert_close($unit::from($base($size)).0, 1.0);
+        assert_close($unit::from($base::from($unit(3.7))).0, 3.7);
+        assert_close($base::from($unit::from($base(3.7))).0, 3.7);
+    }
+}
+
+#[test]
+fn test_round_trip_length() {
+    round_trip!(Meter, Foot, 0.3048);
+    round_trip!(Meter, Yard, 0.9144);
+    round_trip!(Meter, Mile, 1609.344);
+    round_trip!(Meter, Kilometer, 1.0e3);
+    round_trip!(Meter, Milimeter, 1.0e-3);
+    round_trip!(Meter, Micrometer, 1.0e-6);
+    round_trip!(Meter, Nanometer, 1.0e-9);
+    round_trip!(Meter, AstronomicalUnit, 149597870700.0);
+    round_trip!(Meter, LightYear, 9.4607e15);
+    round_trip!(Meter, Parsec, 3.08567758149137e16);
+}
+
+#[test]
+fn test_round_trip_time() {
+    round_trip!(Second, Minute, 60.0);
+    round_trip!(Second, Hour, 3600.0);
+    round_trip!(Second, Day, 86400.0);
+    round_trip!(Second, Week, 604800.0);
+    round_trip!(Second, Month, 2592000.0);
+    round_trip!(Second, Year, 31557600.0);
+    round_trip!(Second, MilYear, 31557600.0e6);
+}
+
+#[test]
+fn test_round_trip_temperature() {
+    round_trip!(KelvinDelta, DegCDelta, 1.0);
+    round_trip!(KelvinDelta, FahrenheitDelta, 5.0 / 9.0);
+    round_trip!(DegCDelta, FahrenheitDelta, 5.0 / 9.0);
+    round_trip!(KelvinPerSecond, DegCPerSecond, 1.0);
+
+    assert_close(DegC::from(Kelvin::from(DegC(21.5))).0, 21.5);
+    assert_close(Kelvin::from(DegC::from(Kelvin(300.0))).0, 300.0);
+    assert_close(DegC::from(Fahrenheit::from(DegC(21.5))).0, 21.5);
+    assert_close(Fahrenheit::from(DegC::from(Fahrenheit(70.0))).0, 70.0);
+}
+
+#[test]
+fn test_numeric_methods() {
+    assert_eq!(Foot(-2.5).abs(), Foot(2.5));
+    assert_eq!(Foot(-2.5).signum(), -1.0);
+    assert_eq!(Foot(3.0).hypot(Foot(4.0)), Foot(5.0));
+    assert_eq!(Hour(2.0).mul_add(3.0, Hour(1.0)), Hour(7.0));
+    assert_eq!(Hour(2.4).round(), Hour(2.0));
+    assert_eq!(DegC(20.0).max(DegC(25.0)), DegC(25.0));
+    assert_eq!(DegC(-300.0).clamp(DegC(-273.15), DegC(100.0)), DegC(-273.15));
+    assert_eq!(Kelvin(300.7).floor(), Kelvin(300.0));
+    assert!(DegC(20.0).is_finite());
+}
@@ -0,0 +1,35 @@
+extern crate simple_units;
+
+use simple_units::si_units::*;
+use simple_units::conversion::*;
+
+fn assert_close(value: f64, expected: f64) {
+    assert!((value - expected).abs() <= 1.0e-12 * expected.abs(), "{} != {}", value, expected);
+}
+
+#[test]
+fn test_transitive_conversion() {
+    assert_close(Mile::from(Foot(5280.0)).0, 1.0);
+    assert_close(Foot::from(Yard(2.0)).0, 6.0);
+    assert_close(Kilometer::from(Mile(1.0)).0, 1.609344);
+    assert_close(Day::from(Hour(48.0)).0, 2.0);
+    assert_close(Minute::from(Week(1.0)).0, 10080.0);
+    assert_close(Millisecond::from(Minute(1.0)).0, 60000.0);
+    assert_close(Gram::from(Tonne(1.5)).0, 1.5e6);
+    assert_close(Hectopascal::from(Megapascal(1.0)).0, 1.0e4);
+    assert_close(Kilojoule::from(Megajoule(2.0)).0, 2000.0);
+    assert_close(Kilowatt::from(Megawatt(2.0)).0, 2000.0);
+    assert_close(ArcMinute::from(Degree(2.0)).0, 120.0);
+    assert_close(Gradian::from(Degree(90.0)).0, 100.0);
+}
+
+#[test]
+fn test_convert_trait() {
+    assert_eq!(Meter(0.3048).to::<Foot>(), Foot(1.0));
+    assert_eq!(Meter(1.0) + Foot(1.0).to::<Meter>(), Meter(1.3048));
+    assert_eq!(Hour(2.0).to::<Minute>(), Minute(120.0));
+    assert_eq!(DegC(10.0).to::<Fahrenheit>(), Fahrenheit(50.0));
+    assert_close(Mile(1.0).in_units_of::<Foot>(), 5280.0);
+    assert_close(Kelvin(283.15).in_units_of::<DegC>(), 10.0);
+    assert_close(Minute(3.0).in_units_of::<Second>(), 180.0);
+}
@@ -0,0 +1,66 @@
+extern crate simple_units;
+
+use simple_units::si_units::*;
+use simple_units::conversion::*;
+use simple_units::display::{UnitDisplay, DisplayStyle};
+
+#[test]
+fn test_display_quantity() {
+    assert_eq!(format!("{}", Meter(2.5)), "2.5 m");
+    assert_eq!(format!("{:e}", Meter2PerSecond(2.0e-13)), "2e-13 m²·s⁻¹");
+    assert_eq!(format!("{}", Joule(3.0)), "3 J");
+    assert_eq!(format!("{}", Newton(3.0)), "3 N");
+    assert_eq!(format!("{}", JoulePerKelvinMol(8.3)), "8.3 m²·kg·s⁻²·K⁻¹·mol⁻¹");
+    assert_eq!(format!("{}", Meter(2.0) / Meter(4.0)), "0.5");
+}
+
+#[test]
+fn test_display_styles() {
+    let d0 = Meter2PerSecond(2.0e-13);
+    assert_eq!(format!("{:e}", d0.display_as(DisplayStyle::Ascii)), "2e-13 m^2/s");
+    assert_eq!(format!("{:e}", d0.display_as(DisplayStyle::Unicode)), "2e-13 m²·s⁻¹");
+    assert_eq!(format!("{:e}", d0.display_as(DisplayStyle::Latex)), "\\SI{2e-13}{\\metre\\squared\\per\\second}");
+
+    let r = JoulePerKelvinMol(8.3);
+    assert_eq!(format!("{}", r.display_as(DisplayStyle::Ascii)), "8.3 m^2*kg/(s^2*K*mol)");
+    assert_eq!(format!("{}", r.display_as(DisplayStyle::Latex)),
+        "\\SI{8.3}{\\metre\\squared\\kilogram\\per\\second\\squared\\per\\kelvin\\per\\mole}");
+
+    assert_eq!(format!("{}", PerSecond(4.0).display_as(DisplayStyle::Ascii)), "4 1/s");
+    assert_eq!(format!("{}", Unitless(4.0).display_as(DisplayStyle::Latex)), "\\num{4}");
+}
+
+#[test]
+fn test_display_format_flags() {
+    assert_eq!(format!("{:.3}", Meter(2.5)), "2.500 m");
+    assert_eq!(format!("{:10.1}", Meter(2.5)), "     2.5 m");
+    assert_eq!(format!("{:<10.1}|", Meter(2.5)), "2.5 m     |");
+    assert_eq!(format!("{:*^9}", Meter(2.5)), "**2.5 m**");
+    assert_eq!(format!("{:+}", Meter(2.5)), "+2.5 m");
+    assert_eq!(format!("{:+}", Meter(-2.5)), "-2.5 m");
+    assert_eq!(format!("{:.2E}", Pascal(101325.0)), "1.01E5 Pa");
+    assert_eq!(format!("{:.1e}", Foot(1500.0).display_as(DisplayStyle::Ascii)), "1.5e3 ft");
+}
+
+#[test]
+fn test_display_unit() {
+    assert_eq!(format!("{}", Foot(3.0)), "3 ft");
+    assert_eq!(format!("{}", Micrometer(500.0)), "500 µm");
+    assert_eq!(format!("{}", Micrometer(500.0).display_as(DisplayStyle::Ascii)), "500 um");
+    assert_eq!(format!("{}", Micrometer(500.0).display_as(DisplayStyle::Latex)), "\\SI{500}{\\micro\\metre}");
+    assert_eq!(format!("{}", DegC(21.5)), "21.5 °C");
+    assert_eq!(format!("{}", DegC(21.5).display_as(DisplayStyle::Ascii)), "21.5 degC");
+    assert_eq!(format!("{}", Fahrenheit(70.0).display_as(DisplayStyle::Latex)), "\\SI{70}{\\degree{}F}");
+    assert_eq!(format!("{}", Kelvin(300.0)), "300 K");
+    assert_eq!(format!("{}", PerDegC(0.5)), "0.5 °C⁻¹");
+    assert_eq!(format!("{}", MilYear(3.0)), "3 Ma");
+}
+
+#[test]
+fn test_display_parse_round_trip() {
+    let value = JoulePerKelvinMol(8.3);
+    let text = format!("{}", value.display_as(DisplayStyle::Ascii));
+    assert_eq!(text.parse::<JoulePerKelvinMol>(), Ok(value));
+    let text = format!("{}", value);
+    assert_eq!(text.parse::<JoulePerKelvinMol>(), Ok(value));
+}
@@ -0,0 +1,52 @@
+extern crate simple_units;
+
+use simple_units::si_units::*;
+use simple_units::kind::Kind;
+
+#[test]
+fn test_tag_untag() {
+    let torque: NewtonMeter = (Newton(2.0) * Meter(3.0)).tag();
+    assert_eq!(torque, NewtonMeter(6.0));
+    assert_eq!(torque.untag(), Joule(6.0));
+    assert_eq!(NewtonMeter::tag(Joule(6.0)), torque);
+
+    // Without tag the product is an energy
+    let energy: Joule = Newton(2.0) * Meter(3.0);
+    assert_eq!(energy, Joule(6.0));
+}
+
+#[test]
+fn test_change_kind() {
+    assert_eq!(Becquerel::tag(Hertz(50.0).untag()), Becquerel(50.0));
+    assert_eq!(Sievert::tag(Gray(2.0).untag()), Sievert(2.0));
+    assert_eq!(Hertz(3.0).untag(), PerSecond(3.0));
+}
+
+#[test]
+fn test_angular_frequency() {
+    let pi = ::std::f64::consts::PI;
+
+    // Explicit: one turn per second
+    assert_eq!(Hertz(1.0).to_angular(), RadianPerSecond(2.0 * pi));
+    assert_eq!(RadianPerSecond(4.0 * pi).to_frequency(), Hertz(2.0));
+
+    // Through the base unit: 1 rad/s is 1/s
+    assert_eq!(RadianPerSecond::from(PerSecond::from(Hertz(1.0))), RadianPerSecond(1.0));
+    assert_eq!(RadianPerSecond::tag(Hertz(1.0).untag()), RadianPerSecond(1.0));
+    assert_eq!(Hertz::from(PerSecond::from(RadianPerSecond(1.0))), Hertz(1.0));
+}
+
+#[test]
+fn test_kind_with_size() {
+    assert_eq!(Barn::tag(Meter2(2.0e-28)), Barn(2.0));
+    assert_eq!(Barn(2.0).untag(), Meter2(2.0e-28));
+    assert_eq!("3 b".parse::<Barn>(), Ok(Barn(3.0)));
+}
+
+#[test]
+fn test_kind_arithmetic() {
+    assert_eq!(NewtonMeter(2.0) + NewtonMeter(3.0), NewtonMeter(5.0));
+    assert_eq!(NewtonMeter(2.0) * 3.0, NewtonMeter(6.0));
+    assert_eq!(format!("{}", NewtonMeter(1.5)), "1.5 N·m");
+    assert_eq!("2 N*m".parse::<NewtonMeter>(), Ok(NewtonMeter(2.0)));
+}
@@ -2,6 +2,7 @@ extern crate simple_units;
 
 use simple_units::si_units::*;
 use simple_units::conversion::*;
+use simple_units::constants::{GAS_CONSTANT, ConstantSet, Codata2006, Codata2014, Codata2018, Codata2022};
 
 #[test]
 fn test_units1() {
@@ -10,7 +11,7 @@ fn test_units1() {
     let grain_size = Meter::from(Micrometer(500.0));
     let diff: PerSecond = d0 / (grain_size * grain_size );
     let energy = JoulePerMol(105.0);
-    let univ_gas_const = JoulePerKelvinMol(8.3144598);
+    let univ_gas_const = GAS_CONSTANT.value;
 
     let temperature1 = DegC(200.0);
     let temperature2 = DegC(300.0);
@@ -18,7 +19,10 @@ fn test_units1() {
     let time1 = Second::from(MilYear(1.0));
     let time2 = Second::from(MilYear(2.0));
 
-    let cooling_rate: KelvinPerSecond = Kelvin::from((temperature2 - temperature1)) / (time2 - time1);
+    let temperature_diff = KelvinDelta::from(temperature2 - temperature1);
+    assert_eq!(temperature_diff, KelvinDelta(100.0));
+
+    let cooling_rate: KelvinPerSecond = temperature_diff / (time2 - time1);
 
     let temperature_k = Kelvin::from(temperature2);
 
@@ -26,8 +30,40 @@ fn test_units1() {
 
     let geometry_factor = 27.0;
 
-    let closure_temp: DegC = DegC::from(energy / ((geometry_factor * tau * diff).ln() * univ_gas_const));
+    let _closure_temp: DegC = DegC::from(Kelvin::from(energy / ((geometry_factor * tau * diff).ln() * univ_gas_const)));
 
     // assert_eq!(closure_temp, DegC(1.0));
 }
 
+// Closure temperature of biotite as in test_units1, with the gas constant of the given CODATA release
+fn closure_temperature<C: ConstantSet>() -> DegC {
+    let d0 = Meter2PerSecond(2.0e-13);
+    let grain_size = Meter::from(Micrometer(500.0));
+    let diff: PerSecond = d0 / (grain_size * grain_size );
+    let energy = JoulePerMol(105.0e3);
+    let univ_gas_const = C::GAS_CONSTANT.value;
+
+    let temperature_diff = KelvinDelta::from(DegC(300.0) - DegC(200.0));
+    let cooling_rate: KelvinPerSecond = temperature_diff / Second::from(MilYear(1.0));
+    let temperature_k = Kelvin::from(DegC(300.0));
+
+    let tau: Second = univ_gas_const * (temperature_k * temperature_k) / (energy * cooling_rate);
+
+    DegC::from(Kelvin::from(energy / ((27.0 * tau * diff).ln() * univ_gas_const)))
+}
+
+#[test]
+fn test_closure_temperature_codata() {
+    assert_eq!(Codata2014::GAS_CONSTANT.value, JoulePerKelvinMol(8.3144598));
+
+    let closure_2014 = closure_temperature::<Codata2014>();
+    let closure_2018 = closure_temperature::<Codata2018>();
+
+    // The exact gas constant since 2019 is the same in 2018 and 2022
+    assert_eq!(closure_2018, closure_temperature::<Codata2022>());
+
+    // Different releases give slightly different results
+    assert!(closure_2014 != closure_2018);
+    assert!(KelvinDelta::from(closure_2014 - closure_2018).abs().0 < 1.0e-3);
+    assert!(KelvinDelta::from(closure_temperature::<Codata2006>() - closure_2018).abs().0 < 1.0e-3);
+}
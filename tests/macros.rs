@@ -0,0 +1,155 @@
+// Every exported macro must work in a module without any imports:
+// all paths inside the macros are absolute ($crate::..., ::std::...).
+
+#[macro_use] extern crate simple_units;
+
+mod unit {
+    init_unit!(Meter);
+    init_unit!(Foot, "ft");
+    init_unit!(Micrometer, "um", "µm", "\\micro\\metre");
+}
+
+mod mul_div {
+    init_unit!(Joule);
+    init_unit!(Meter);
+    init_unit!(Newton);
+    init_unit!(Meter2);
+    mul_div_unit!(Newton, Meter, Joule);
+    mul_div_unit!(Meter, Meter2);
+}
+
+mod inverse {
+    init_unit!(Meter);
+    init_unit!(PerMeter);
+    inverse_unit!(Meter, PerMeter);
+}
+
+mod unit_and_inverse {
+    init_unit_and_inverse!(Meter, PerMeter);
+}
+
+mod power3 {
+    init_unit_and_inverse!(Second, PerSecond);
+    init_unit_and_inverse!(Second2, PerSecond2);
+    init_unit_and_inverse!(Second3, PerSecond3);
+    power3_unit!(Second, Second2, Second3, PerSecond, PerSecond2, PerSecond3);
+}
+
+mod combine {
+    init_unit!(Meter);
+    init_unit!(PerMeter);
+    init_unit!(Second);
+    init_unit!(PerSecond);
+    init_unit!(MeterSecond);
+    init_unit!(MeterPerSecond);
+    init_unit!(SecondPerMeter);
+    init_unit!(PerMeterSecond);
+    combine_unit!(Meter, PerMeter, Second, PerSecond, MeterSecond, MeterPerSecond, SecondPerMeter, PerMeterSecond);
+}
+
+mod absolute {
+    init_unit!(DegCDelta);
+    init_absolute_unit!(DegC, DegCDelta);
+    init_absolute_unit!(DegF, DegCDelta, "degF", "°F", "\\degree{}F");
+}
+
+mod convert {
+    init_unit!(Second);
+    init_unit!(Minute);
+    init_unit!(Hour);
+    convert_unit!(Second, Minute, size = 60.0);
+    convert_unit!(Minute, Hour, |value| value / 60.0, |value| value * 60.0);
+    compare_unit!(Second, Minute);
+}
+
+mod prefix {
+    init_unit!(Gram);
+    prefix_unit!(Gram, Kilogram, 1.0e3);
+    prefix_unit!(Gram, Milligram, 1.0e-3, "mg");
+}
+
+mod quantity {
+    init_quantity!(Second, Z0, Z0, P1, Z0, Z0, Z0, Z0);
+    init_quantity_and_inverse!(Meter, PerMeter, P1, Z0, Z0, Z0, Z0, Z0, Z0);
+}
+
+mod parse {
+    init_quantity!(Second, Z0, Z0, P1, Z0, Z0, Z0, Z0);
+    init_unit!(Minute);
+    convert_unit!(Second, Minute, size = 60.0);
+    parse_unit!(Minute, Second);
+}
+
+mod kind {
+    init_quantity!(Joule, P2, P1, N2, Z0, Z0, Z0, Z0);
+    init_kind!(Torque, Joule, "N*m");
+    init_kind!(Erg, Joule, size = 1.0e-7, "erg");
+}
+
+mod angle {
+    use simple_units::si_units::Radian;
+
+    init_unit!(Turn);
+    convert_unit!(Radian, Turn, size = 2.0 * ::std::f64::consts::PI);
+    angle_unit!(Turn);
+}
+
+// Types and a macro with the names of the prelude, the macros must not refer to them
+#[allow(dead_code, unused_macros)]
+mod shadowed {
+    struct Result;
+    struct Option;
+    struct String;
+    struct From;
+    struct Iterator;
+
+    macro_rules! panic {
+        ($($argument:tt)*) => { compile_error!("the macros must use ::std::panic") };
+    }
+
+    init_quantity!(Second, Z0, Z0, P1, Z0, Z0, Z0, Z0);
+    init_unit!(Minute, "min");
+    init_unit!(Hour, "h");
+    convert_unit!(Second, Minute, size = 60.0);
+    convert_unit!(Second, Hour, size = 3600.0);
+    convert_via_base!(Second, Minute, Hour);
+    parse_unit!(Minute, Second);
+    init_unit!(DegCDelta);
+    init_absolute_unit!(DegC, DegCDelta);
+    init_kind!(Age, Second, "a");
+    parse_unit!(Age, Second, "a");
+
+    pub fn assert_units() {
+        assert_unit_eq!(Minute::from(Hour(1.0)), Minute(60.0), rel = 1.0e-12);
+        assert_unit_eq!(Second(1.0), Second(1.0 + 1.0e-14), abs = 1.0e-12);
+        assert_unit_ne!(Hour(1.0), Hour(1.1), ulps = 4);
+    }
+}
+
+#[test]
+fn test_macros_without_imports() {
+    use simple_units::kind::Kind;
+
+    assert_eq!(format!("{}", unit::Micrometer(2.0)), "2 µm");
+    assert_eq!(mul_div::Newton(2.0) * mul_div::Meter(3.0), mul_div::Joule(6.0));
+    assert_eq!(mul_div::Meter(2.0) * mul_div::Meter(3.0), mul_div::Meter2(6.0));
+    assert_eq!(1.0 / inverse::Meter(2.0), inverse::PerMeter(0.5));
+    assert_eq!(unit_and_inverse::Meter(2.0) * unit_and_inverse::PerMeter(3.0), 6.0);
+    assert_eq!(power3::Second(2.0) * power3::Second2(3.0), power3::Second3(6.0));
+    assert_eq!(combine::Meter(6.0) / combine::Second(2.0), combine::MeterPerSecond(3.0));
+    assert_eq!(absolute::DegC(30.0) - absolute::DegC(20.0), absolute::DegCDelta(10.0));
+    assert_eq!(convert::Minute::from(convert::Second(120.0)), convert::Minute(2.0));
+    assert_eq!(convert::Minute::from(convert::Hour(2.0)), convert::Minute(120.0));
+    assert!(convert::Second(90.0) > convert::Minute(1.0));
+    assert_eq!(prefix::Gram::from(prefix::Kilogram(2.0)), prefix::Gram(2000.0));
+    assert_eq!(quantity::Meter(2.0) * quantity::PerMeter(3.0), 6.0);
+    assert_eq!("2 h".parse::<parse::Minute>(), Ok(parse::Minute(120.0)));
+    assert_eq!(kind::Torque::tag(kind::Joule(2.0)), kind::Torque(2.0));
+    assert_eq!(kind::Erg(1.0e7).untag(), kind::Joule(1.0));
+    assert!((angle::Turn(0.25).sin().0 - 1.0).abs() < 1.0e-15);
+    assert_eq!("2 h".parse::<shadowed::Minute>(), Ok(shadowed::Minute(120.0)));
+    assert_eq!(shadowed::Age::tag(shadowed::Second(2.0)), shadowed::Age(2.0));
+    assert_eq!([shadowed::Minute(1.0), shadowed::Minute(2.0)].iter().sum::<shadowed::Minute>(), shadowed::Minute(3.0));
+    assert!(shadowed::Hour(1.0) > shadowed::Minute(59.0));
+    shadowed::assert_units();
+}
@@ -0,0 +1,34 @@
+extern crate simple_units;
+
+use simple_units::si_units::*;
+use simple_units::conversion::*;
+
+#[test]
+fn test_operators() {
+    assert_eq!(-Foot(2.0), Foot(-2.0));
+    assert_eq!(Degree(370.0) % Degree(360.0), Degree(10.0));
+
+    let mut duration = Hour(1.0);
+    duration += Hour(2.0);
+    duration -= Hour(0.5);
+    duration *= 2.0;
+    duration /= 5.0;
+    assert_eq!(duration, Hour(1.0));
+
+    let laps = [Minute(1.5), Minute(2.0), Minute(2.5)];
+    assert_eq!(laps.iter().sum::<Minute>(), Minute(6.0));
+    assert_eq!(laps.iter().copied().sum::<Minute>(), Minute(6.0));
+
+    // An empty sum is +0, not -0
+    let empty: [Minute; 0] = [];
+    assert!(empty.iter().sum::<Minute>().0.is_sign_positive());
+    assert!(empty.iter().copied().sum::<Minute>().0.is_sign_positive());
+    assert_eq!(format!("{}", empty.iter().sum::<Minute>()), "0 min");
+
+    // Absolute temperatures only change by a difference
+    let mut temperature = DegC(20.0);
+    temperature += DegCDelta(5.0);
+    temperature -= DegCDelta(1.0);
+    assert_eq!(temperature, DegC(24.0));
+    assert_eq!([DegCDelta(1.0), DegCDelta(2.0)].iter().sum::<DegCDelta>(), DegCDelta(3.0));
+}
@@ -0,0 +1,94 @@
+extern crate simple_units;
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use simple_units::si_units::*;
+use simple_units::conversion::*;
+use simple_units::order::Ordered;
+
+#[test]
+fn test_partial_ord() {
+    assert!(Meter(1.0) < Meter(2.0));
+    assert!(Joule(-1.0) <= Joule(-1.0));
+    assert!(DegC(310.0) > DegC(300.0));
+    assert!(Foot(3.0) < Foot(4.0));
+    assert!(Kelvin(300.0) >= Kelvin(300.0));
+    assert!(Unitless::new(0.5) < 1.0);
+    assert_eq!(Second(f64::NAN).partial_cmp(&Second(1.0)), None);
+
+    let temperature = DegC(350.0);
+    assert!(temperature > DegC(300.0));
+}
+
+#[test]
+fn test_total_cmp() {
+    assert_eq!(Meter(1.0).total_cmp(&Meter(2.0)), Ordering::Less);
+    assert_eq!(Meter(-0.0).total_cmp(&Meter(0.0)), Ordering::Less);
+    assert_eq!(Hour(f64::NAN).total_cmp(&Hour(f64::INFINITY)), Ordering::Greater);
+    assert_eq!(DegC(20.0).total_cmp(&DegC(20.0)), Ordering::Equal);
+
+    let mut lengths = [Meter(3.0), Meter(f64::NAN), Meter(-1.0), Meter(2.0)];
+    lengths.sort_by(Meter::total_cmp);
+    assert_eq!(&lengths[..3], &[Meter(-1.0), Meter(2.0), Meter(3.0)]);
+    assert!(lengths[3].is_nan());
+
+    let mut durations = vec![Minute(3.0), Minute(1.0), Minute(2.0)];
+    durations.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(durations, vec![Minute(1.0), Minute(2.0), Minute(3.0)]);
+}
+
+#[test]
+fn test_compare_different_units() {
+    assert!(Meter(1.0) > Foot(3.0));
+    assert!(Foot(4.0) > Meter(1.0));
+    assert!(Meter(0.3048) == Foot(1.0));
+    assert!(Foot(1.0) == Meter(0.3048));
+    assert!(Mile(1.0) == Foot(5280.0));
+    assert!(Kilometer(1.0) < Mile(1.0));
+    assert!(Hour(1.0) > Minute(59.0));
+    assert!(Second(3600.0) == Hour(1.0));
+    assert!(Tonne(1.0) > Gram(999.0));
+    assert!(Degree(180.0) < Radian(3.2));
+    assert!(ArcMinute(60.0) == Degree(1.0));
+
+    assert!(DegC(0.0) == Kelvin(273.15));
+    assert!(DegC(100.0) > Fahrenheit(200.0));
+    assert!(Kelvin(0.0) < DegC(-273.0));
+    assert!(DegCDelta(1.0) > FahrenheitDelta(1.0));
+    assert!(KelvinDelta(1.0) == DegCDelta(1.0));
+}
+
+#[test]
+fn test_compare_symmetric() {
+    // Values that are not exactly equal after rounding, in both directions the same
+    assert_eq!(Meter(0.009144) == Foot(0.03), Foot(0.03) == Meter(0.009144));
+    assert_eq!(Meter(0.009144).partial_cmp(&Foot(0.03)), Foot(0.03).partial_cmp(&Meter(0.009144)).map(Ordering::reverse));
+    assert_eq!(DegC(0.01) == Fahrenheit(32.018), Fahrenheit(32.018) == DegC(0.01));
+    assert_eq!(DegC(0.01).partial_cmp(&Fahrenheit(32.018)), Fahrenheit(32.018).partial_cmp(&DegC(0.01)).map(Ordering::reverse));
+
+    for &(meter, foot) in &[(0.009144, 0.03), (0.1, 0.328084), (1.0e-3, 3.28084e-3), (2.54e-2, 1.0 / 12.0)] {
+        assert_eq!(Meter(meter) == Foot(foot), Foot(foot) == Meter(meter), "{} m, {} ft", meter, foot);
+        assert_eq!(Meter(meter) < Foot(foot), Foot(foot) > Meter(meter), "{} m, {} ft", meter, foot);
+    }
+}
+
+#[test]
+fn test_ordered_btree_map() {
+    let mut map = BTreeMap::new();
+    map.insert(Ordered(Meter(2.0)), "b");
+    map.insert(Ordered(Meter(1.0)), "a");
+    map.insert(Ordered(Meter(f64::NAN)), "nan");
+    map.insert(Ordered(Meter(3.0)), "c");
+
+    let keys: Vec<Meter> = map.keys().map(|key| key.into_inner()).collect();
+    assert_eq!(&keys[..3], &[Meter(1.0), Meter(2.0), Meter(3.0)]);
+    assert_eq!(map.get(&Ordered(Meter(2.0))), Some(&"b"));
+    assert_eq!(map.get(&Ordered(Meter(f64::NAN))), Some(&"nan"));
+
+    let mut temperatures = BTreeMap::new();
+    temperatures.insert(Ordered::from(DegC(20.0)), 1);
+    temperatures.insert(Ordered::from(DegC(20.0)), 2);
+    assert_eq!(temperatures.len(), 1);
+    assert_eq!(format!("{}", temperatures.keys().next().unwrap()), "20 °C");
+}
@@ -0,0 +1,160 @@
+extern crate simple_units;
+
+use simple_units::si_units::*;
+use simple_units::conversion::*;
+use simple_units::parse::{parse_quantity, parse_unit_expression, ParseError};
+
+fn assert_close(value: f64, expected: f64) {
+    assert!((value - expected).abs() <= 1.0e-12 * expected.abs(), "{} != {}", value, expected);
+}
+
+#[test]
+fn test_parse_si_unit() {
+    assert_eq!("2.5 m".parse::<Meter>(), Ok(Meter(2.5)));
+    assert_eq!("2.5m".parse::<Meter>(), Ok(Meter(2.5)));
+    assert_eq!("2.0e-13 m^2/s".parse::<Meter2PerSecond>(), Ok(Meter2PerSecond(2.0e-13)));
+    assert_eq!("3 kg m/s^2".parse::<Newton>(), Ok(Newton(3.0)));
+    assert_eq!("3 kg*m*s^-2".parse::<Newton>(), Ok(Newton(3.0)));
+    assert_eq!("3 kg·m·s⁻²".parse::<Newton>(), Ok(Newton(3.0)));
+    assert_eq!("8.3 J/(K mol)".parse::<JoulePerKelvinMol>(), Ok(JoulePerKelvinMol(8.3)));
+    assert_eq!("4 1/s".parse::<PerSecond>(), Ok(PerSecond(4.0)));
+    assert_eq!("0.5".parse::<Unitless>(), Ok(Unitless(0.5)));
+}
+
+#[test]
+fn test_parse_prefix() {
+    assert_close("500 um".parse::<Meter>().unwrap().0, 500.0e-6);
+    assert_close("500 µm".parse::<Meter>().unwrap().0, 500.0e-6);
+    assert_close("105 kJ/mol".parse::<JoulePerMol>().unwrap().0, 105.0e3);
+    assert_close("3 Ma".parse::<Second>().unwrap().0, 3.0 * 31557600.0e6);
+    assert_close("2 g".parse::<Kilogram>().unwrap().0, 2.0e-3);
+    assert_close("2 mg".parse::<Kilogram>().unwrap().0, 2.0e-6);
+    assert_close("1 hPa".parse::<Pascal>().unwrap().0, 100.0);
+    assert_eq!("3 Pa".parse::<Pascal>(), Ok(Pascal(3.0)));
+    assert_eq!("2 mol".parse::<Mol>(), Ok(Mol(2.0)));
+    assert_eq!("2 min".parse::<Second>(), Ok(Second(120.0)));
+}
+
+#[test]
+fn test_parse_conversion_unit() {
+    assert_close("500 um".parse::<Micrometer>().unwrap().0, 500.0);
+    assert_close("1 ft".parse::<Meter>().unwrap().0, 0.3048);
+    assert_close("3 ft".parse::<Yard>().unwrap().0, 1.0);
+    assert_close("90 min".parse::<Hour>().unwrap().0, 1.5);
+    assert_close("105 kJ/mol".parse::<JoulePerMol>().unwrap().0, 105.0e3);
+}
+
+#[test]
+fn test_parse_temperature() {
+    assert_eq!("300 K".parse::<Kelvin>(), Ok(Kelvin(300.0)));
+    assert_close("0 degC".parse::<Kelvin>().unwrap().0, 273.15);
+    assert_close("0 °C".parse::<Kelvin>().unwrap().0, 273.15);
+    assert_close("32 degF".parse::<Kelvin>().unwrap().0, 273.15);
+    assert_close("212 degF".parse::<DegC>().unwrap().0, 100.0);
+    assert_close("300 K".parse::<DegC>().unwrap().0, 26.85);
+
+    // No offset for differences
+    assert_close("10 degC".parse::<KelvinDelta>().unwrap().0, 10.0);
+    assert_close("18 degF".parse::<DegCDelta>().unwrap().0, 10.0);
+    assert_close("2 K/s".parse::<DegCPerSecond>().unwrap().0, 2.0);
+}
+
+#[test]
+fn test_parse_dynamic() {
+    let parsed = parse_quantity("105 kJ/mol").unwrap();
+    assert_eq!(parsed.value, 105.0);
+    assert_eq!(parsed.unit.exponents, [2, 1, -2, 0, -1, 0, 0]);
+    assert_close(parsed.si_value(), 105.0e3);
+
+    let unit = parse_unit_expression("km/h").unwrap();
+    assert_eq!(unit.exponents, [1, 0, -1, 0, 0, 0, 0]);
+    assert_close(unit.size, 1000.0 / 3600.0);
+}
+
+#[test]
+fn test_parse_error() {
+    assert_eq!("2 s".parse::<Meter>(), Err(ParseError::DimensionMismatch {
+        expected: [1, 0, 0, 0, 0, 0, 0], found: [0, 0, 1, 0, 0, 0, 0] }));
+    assert_eq!("2 furlong".parse::<Meter>(), Err(ParseError::UnknownUnit("furlong".to_string())));
+    assert_eq!("m".parse::<Meter>(), Err(ParseError::InvalidNumber("m".to_string())));
+    assert!("2 m^".parse::<Meter>().is_err());
+    assert!("2 (m".parse::<Meter>().is_err());
+    assert!("2 m)".parse::<Meter>().is_err());
+    assert!("2 ft".parse::<Hour>().is_err());
+    assert!("2 kJ".parse::<JoulePerMol>().is_err());
+}
+
+#[test]
+fn test_parse_exponent_overflow() {
+    for input in &["1 (m^2147483647)^2", "1 m⁹⁹⁹⁹⁹⁹⁹⁹⁹⁹⁹", "1 m^2147483647 m", "1 m^-2147483647 / m^2"] {
+        match input.parse::<Meter>() {
+            Err(ParseError::InvalidExpression(_)) => {}
+            other => panic!("{}: {:?}", input, other),
+        }
+    }
+}
+
+#[test]
+fn test_parse_year_prefixes() {
+    assert_eq!(parse_quantity("1 ha"), Err(ParseError::UnknownUnit("ha".to_string())));
+    assert_close(Second::from("2 ka".parse::<Year>().unwrap()).0, Second::from(Year(2000.0)).0);
+    assert_close("3 Ma".parse::<MilYear>().unwrap().0, 3.0);
+    assert_close("1 Ga".parse::<MilYear>().unwrap().0, 1000.0);
+}
+
+#[test]
+fn test_parse_bare_integer() {
+    for input in &["1 000 m", "2 500 um", "3 2 m", "5 1 m", "5 m 2"] {
+        match input.parse::<Meter>() {
+            Err(ParseError::InvalidExpression(_)) => {}
+            other => panic!("{}: {:?}", input, other),
+        }
+    }
+    assert_eq!("5 1/s".parse::<PerSecond>(), Ok(PerSecond(5.0)));
+    assert_eq!(parse_unit_expression("1").unwrap().exponents, [0; 7]);
+}
+
+#[test]
+fn test_parse_offset_single_unit() {
+    assert_close("10 (degC)".parse::<Kelvin>().unwrap().0, 283.15);
+    assert_close("10 degC^1".parse::<Kelvin>().unwrap().0, 283.15);
+    assert_close("50 ((degF))".parse::<DegC>().unwrap().0, 10.0);
+    assert_close("10 degC/s".parse::<KelvinPerSecond>().unwrap().0, 10.0);
+    assert_eq!(parse_unit_expression("J/(kg degC)").unwrap().offset, 0.0);
+    assert_eq!(parse_unit_expression("degC^2").unwrap().offset, 0.0);
+}
+
+#[test]
+fn test_parse_aliases() {
+    assert_eq!("5 metre".parse::<Meter>(), Ok(Meter(5.0)));
+    assert_eq!("3 sec".parse::<Second>(), Ok(Second(3.0)));
+    assert_close("2 hr".parse::<Minute>().unwrap().0, 120.0);
+    assert_close("3 Myr".parse::<MilYear>().unwrap().0, 3.0);
+    assert_close("4 micron".parse::<Micrometer>().unwrap().0, 4.0);
+    assert_close("2 metre/sec".parse::<MeterPerSecond>().unwrap().0, 2.0);
+    assert_close("100 grad".parse::<Degree>().unwrap().0, 90.0);
+    assert_eq!("100 grad".parse::<Steradian>(), Err(ParseError::UnknownUnit("grad".to_string())));
+    assert_eq!(parse_quantity("3 cubits"), Err(ParseError::UnknownUnit("cubits".to_string())));
+}
+
+#[test]
+fn test_parse_names() {
+    assert_eq!("5 meter".parse::<Meter>(), Ok(Meter(5.0)));
+    assert_eq!("5 meters".parse::<Meter>(), Ok(Meter(5.0)));
+    assert_eq!("5 seconds".parse::<Second>(), Ok(Second(5.0)));
+    assert_eq!("5 Meter".parse::<Meter>(), Ok(Meter(5.0)));
+    assert_close("3 meters/second".parse::<MeterPerSecond>().unwrap().0, 3.0);
+    assert_close("2 hours".parse::<Minute>().unwrap().0, 120.0);
+    assert_close("100 gradians".parse::<Degree>().unwrap().0, 90.0);
+    assert_eq!("100 gradians".parse::<Steradian>(), Err(ParseError::UnknownUnit("gradians".to_string())));
+}
+
+#[test]
+fn test_value_in() {
+    let parsed = parse_quantity("300 degC").unwrap();
+    assert_eq!(parsed.value_in(&parse_unit_expression("K").unwrap()), Ok(573.15));
+
+    let parsed = parse_quantity("90 min").unwrap();
+    assert_eq!(parsed.value_in(&parse_unit_expression("h").unwrap()), Ok(1.5));
+    assert!(parsed.value_in(&parse_unit_expression("m").unwrap()).is_err());
+}
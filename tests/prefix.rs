@@ -0,0 +1,39 @@
+#[macro_use] extern crate simple_units;
+
+use simple_units::si_units::*;
+use simple_units::conversion::*;
+use simple_units::prefix::*;
+
+prefix_unit!(Hour, Millihour, MILLI);
+prefix_unit!(JoulePerMol, KilojoulePerMol, KILO);
+
+#[test]
+fn test_prefix_values() {
+    assert_eq!(YOTTA, 1.0e24);
+    assert_eq!(YOCTO, 1.0e-24);
+    assert_eq!(MILLI * KILO, 1.0);
+    assert_eq!(KIBI, 1024.0);
+    assert_eq!(MEBI, 1048576.0);
+}
+
+#[test]
+fn test_prefixed_si_unit() {
+    assert_eq!(Meter::from(Kilometer(2.5)), Meter(2500.0));
+    assert_eq!(Millisecond::from(Second(1.5)), Millisecond(1500.0));
+    assert_eq!(Pascal::from(Megapascal(3.0)), Pascal(3.0e6));
+    assert_eq!(Kilojoule::from(Joule(105.0e3)), Kilojoule(105.0));
+}
+
+#[test]
+fn test_kilogram() {
+    assert_eq!(Kilogram::from(Gram(1500.0)), Kilogram(1.5));
+    assert_eq!(Gram::from(Kilogram(1.5)), Gram(1500.0));
+    assert_eq!(Kilogram::from(Milligram(1.0)), Kilogram(1.0e-6));
+    assert_eq!(Kilogram::from(Tonne(2.0)), Kilogram(2000.0));
+}
+
+#[test]
+fn test_prefix_any_unit() {
+    assert_eq!(Hour::from(Millihour(500.0)), Hour(0.5));
+    assert_eq!(JoulePerMol::from(KilojoulePerMol(105.0)), JoulePerMol(105000.0));
+}
@@ -0,0 +1,131 @@
+extern crate simple_units;
+
+use simple_units::si_units::*;
+use simple_units::quantity::Quantity;
+use simple_units::dimension::{Dimension, Length, Time};
+use simple_units::dimension::{N2, N1, Z0, P2, P3};
+
+#[test]
+fn test_any_combination() {
+    let value = Meter3(2.0) * Kilogram(3.0);
+    assert_eq!(value / Meter3(2.0), Kilogram(3.0));
+
+    let energy_density = Joule(12.0) / Meter3(4.0);
+    assert_eq!(energy_density, Pascal(3.0));
+}
+
+#[test]
+fn test_derived_units() {
+    assert_eq!(Newton(2.0) * Meter(3.0), Joule(6.0));
+    assert_eq!(Joule(6.0) / Second(2.0), Watt(3.0));
+    assert_eq!(Kilogram(2.0) * MeterPerSecond2(4.5), Newton(9.0));
+    assert_eq!(Pascal(3.0) * Meter2(2.0), Newton(6.0));
+    assert_eq!(JoulePerKelvinMol(2.0) * KelvinDelta(3.0), JoulePerMol(6.0));
+    assert_eq!(PerSecond2(2.0) * Meter2Second(3.0), Meter2PerSecond(6.0));
+}
+
+#[test]
+fn test_dimensionless() {
+    let ratio = Meter(6.0) / Meter(3.0);
+    assert_eq!(ratio, Unitless(2.0));
+    assert_eq!(ratio, 2.0);
+    assert_eq!(f64::from(ratio), 2.0);
+    assert_eq!((Second(1.0) * PerSecond(1.0)).ln(), 0.0);
+}
+
+#[test]
+fn test_migration_from_newtypes() {
+    // The replacements listed in the change log
+    let r = (Meter(2.0) / Meter(1.0)).value();
+    let p: f64 = (Meter(2.0) * PerMeter(3.0)).into();
+    let m = Meter(1.5);
+    let x = m.value();
+    assert_eq!((r, p, x, m.0), (2.0, 6.0, 1.5, 1.5));
+}
+
+#[test]
+fn test_inverse_quantity() {
+    assert_eq!(2.0 / Second(4.0), PerSecond(0.5));
+    assert_eq!(1.0 / PerKelvin(0.5), KelvinDelta(2.0));
+}
+
+#[test]
+fn test_generic_quantity() {
+    let length: Quantity<Length> = Quantity::new(3.0);
+    assert_eq!(length, Meter(3.0));
+    assert_eq!(length.value(), 3.0);
+}
+
+#[test]
+fn test_exponents() {
+    assert_eq!(Length::EXPONENTS, [1, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(Time::EXPONENTS, [0, 0, 1, 0, 0, 0, 0]);
+    assert_eq!(format!("{:?}", Joule(1.5)), "Quantity(1.5, [2, 1, -2, 0, 0, 0, 0])");
+}
+
+#[test]
+fn test_roots() {
+    assert_eq!(Meter2(4.0).sqrt(), Meter(2.0));
+    assert_eq!(Meter3(8.0).cbrt(), Meter(2.0));
+    assert_eq!(PerSecond2(9.0).sqrt(), PerSecond(3.0));
+    assert_eq!(Unitless(4.0).sqrt(), Unitless(2.0));
+
+    // Diffusion length
+    let length: Meter = (Meter2PerSecond(2.0e-13) * Second(2.0e13)).sqrt();
+    assert_eq!(length, Meter(2.0));
+}
+
+#[test]
+fn test_powi() {
+    assert_eq!(Meter(2.0).powi::<P2>(), Meter2(4.0));
+    assert_eq!(Meter(2.0).powi::<P3>(), Meter3(8.0));
+    assert_eq!(Second(2.0).powi::<N1>(), PerSecond(0.5));
+    assert_eq!(Second(2.0).powi::<N2>(), PerSecond2(0.25));
+    assert_eq!(Meter(2.0).powi::<Z0>(), Unitless(1.0));
+}
+
+#[test]
+fn test_numeric_methods() {
+    assert_eq!(Meter(-2.5).abs(), Meter(2.5));
+    assert_eq!(Meter(-2.5).signum(), -1.0);
+    assert_eq!(Meter(1.0).min(Meter(2.0)), Meter(1.0));
+    assert_eq!(Meter(1.0).max(Meter(2.0)), Meter(2.0));
+    assert_eq!(Meter(3.0).clamp(Meter(0.0), Meter(2.0)), Meter(2.0));
+    assert_eq!(Second(2.5).floor(), Second(2.0));
+    assert_eq!(Second(2.5).ceil(), Second(3.0));
+    assert_eq!(Second(2.5).round(), Second(3.0));
+    assert!(Meter(f64::NAN).is_nan());
+    assert!(!Meter(f64::INFINITY).is_finite());
+    assert_eq!(Meter(3.0).hypot(Meter(4.0)), Meter(5.0));
+    assert_eq!(MeterPerSecond(2.0).mul_add(3.0, MeterPerSecond(1.0)), MeterPerSecond(7.0));
+}
+
+#[test]
+fn test_operators() {
+    assert_eq!(-Meter(2.0), Meter(-2.0));
+    assert_eq!(Second(7.5) % Second(2.0), Second(1.5));
+
+    let mut distance = Meter(1.0);
+    distance += Meter(2.0);
+    assert_eq!(distance, Meter(3.0));
+    distance -= Meter(0.5);
+    assert_eq!(distance, Meter(2.5));
+    distance *= 4.0;
+    assert_eq!(distance, Meter(10.0));
+    distance /= 5.0;
+    assert_eq!(distance, Meter(2.0));
+}
+
+#[test]
+fn test_sum_product() {
+    let velocities = [MeterPerSecond(1.0), MeterPerSecond(2.5), MeterPerSecond(3.5)];
+    assert_eq!(velocities.iter().copied().sum::<MeterPerSecond>(), MeterPerSecond(7.0));
+    assert_eq!(velocities.iter().sum::<MeterPerSecond>(), MeterPerSecond(7.0));
+    assert_eq!(Vec::<Joule>::new().into_iter().sum::<Joule>(), Joule(0.0));
+    assert!(Vec::<Joule>::new().into_iter().sum::<Joule>().0.is_sign_positive());
+    assert!(Vec::<Meter>::new().iter().sum::<Meter>().0.is_sign_positive());
+
+    let factors = [Unitless::new(2.0), Unitless::new(3.0), Unitless::new(0.5)];
+    assert_eq!(factors.iter().product::<Unitless>(), 3.0);
+    assert_eq!(factors.iter().copied().product::<Unitless>(), Unitless::new(3.0));
+}
@@ -0,0 +1,97 @@
+extern crate simple_units;
+
+use simple_units::si_units::*;
+use simple_units::conversion::*;
+use simple_units::display::{UnitDisplay, DisplayStyle};
+use simple_units::parse::parse_unit_expression;
+use simple_units::registry::{self, lookup, units, with_dimension};
+
+fn assert_close(value: f64, expected: f64) {
+    assert!((value - expected).abs() <= 1.0e-12 * expected.abs(), "{} != {}", value, expected);
+}
+
+#[test]
+fn test_all_symbols_parse() {
+    for unit in units() {
+        assert!(parse_unit_expression(unit.symbol).is_ok(), "{}: '{}'", unit.type_name, unit.symbol);
+        for alias in unit.aliases {
+            let expression = parse_unit_expression(alias).unwrap_or_else(|error| panic!("{}: {}", unit.type_name, error));
+            assert_eq!(expression.exponents, unit.dimension(), "{}", alias);
+            assert_close(expression.size, unit.size());
+        }
+    }
+}
+
+#[test]
+fn test_type_names_unique() {
+    for (i, unit) in units().iter().enumerate() {
+        assert!(units()[i + 1..].iter().all(|other| other.type_name != unit.type_name), "{}", unit.type_name);
+    }
+}
+
+#[test]
+fn test_lookup() {
+    for name in &["m", "metre", "meter", "meters", "Meter", "METER"] {
+        assert_eq!(lookup(name).unwrap().type_name, "Meter");
+    }
+
+    assert_eq!(lookup("feet").unwrap().type_name, "Foot");
+    assert_eq!(lookup("µm").unwrap().type_name, "Micrometer");
+    assert_eq!(lookup("°C").unwrap().type_name, "DegC");
+    assert_eq!(lookup("degC").unwrap().type_name, "DegC");
+    assert_eq!(lookup("K").unwrap().type_name, "Kelvin");
+    assert_eq!(lookup("kelvin difference").unwrap().type_name, "KelvinDelta");
+    assert_eq!(lookup("degrees Celsius").unwrap().type_name, "DegC");
+    assert_eq!(lookup("J/(K*mol)").unwrap().type_name, "JoulePerKelvinMol");
+    assert_eq!(lookup("Ω").unwrap().type_name, "Ohm");
+
+    // Symbols are case sensitive
+    assert_eq!(lookup("s").unwrap().type_name, "Second");
+    assert_eq!(lookup("S").unwrap().type_name, "Siemens");
+    assert_eq!(lookup("mm").unwrap().type_name, "Milimeter");
+    assert_eq!(lookup("Mm"), None);
+
+    assert_eq!(lookup("metres per second").unwrap().type_name, "MeterPerSecond");
+    assert_eq!(lookup("Metric Ton").unwrap().type_name, "Tonne");
+
+    assert_eq!(lookup("furlong"), None);
+    assert_eq!(lookup(""), None);
+}
+
+#[test]
+fn test_unit_info() {
+    let foot = lookup("ft").unwrap();
+    assert_eq!(foot.name, "foot");
+    assert_eq!(foot.plural, "feet");
+    assert_eq!(foot.dimension(), [1, 0, 0, 0, 0, 0, 0]);
+    assert_close(foot.size(), Meter::from(Foot(1.0)).0);
+    assert!(foot.names().contains(&"Foot"));
+
+    assert_close(lookup("Year").unwrap().size(), Second::from(Year(1.0)).0);
+    assert_close(lookup("Gram").unwrap().size(), Kilogram::from(Gram(1.0)).0);
+    assert_close(lookup("Degree").unwrap().size(), Radian::from(Degree(1.0)).0);
+
+    let degc = lookup("DegC").unwrap();
+    assert!(degc.absolute);
+    assert_close(degc.expression().offset, 273.15);
+    assert_eq!(lookup("DegCDelta").unwrap().expression().offset, 0.0);
+}
+
+#[test]
+fn test_symbols_match_display() {
+    assert_eq!(lookup("Meter2PerSecond").unwrap().symbol, Meter2PerSecond::display_symbol(DisplayStyle::Ascii));
+    assert_eq!(lookup("Volt").unwrap().symbol, Volt::display_symbol(DisplayStyle::Ascii));
+    assert_eq!(lookup("Hour").unwrap().symbol, Hour::display_symbol(DisplayStyle::Ascii));
+    assert_eq!(lookup("DegC").unwrap().symbol, DegC::display_symbol(DisplayStyle::Ascii));
+}
+
+#[test]
+fn test_with_dimension() {
+    let lengths: Vec<&str> = with_dimension([1, 0, 0, 0, 0, 0, 0]).map(|unit| unit.type_name).collect();
+    assert!(lengths.contains(&"Meter"));
+    assert!(lengths.contains(&"Foot"));
+    assert!(lengths.contains(&"Parsec"));
+    assert!(!lengths.contains(&"Second"));
+
+    assert_eq!(registry::with_dimension([0, 0, -1, 0, 0, 0, 0]).count(), 4);
+}
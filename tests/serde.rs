@@ -0,0 +1,122 @@
+#![cfg(feature = "serde")]
+
+extern crate simple_units;
+extern crate serde_json;
+extern crate bincode;
+#[macro_use] extern crate serde_derive;
+#[macro_use] extern crate simple_units_derive;
+
+use simple_units::si_units::*;
+use simple_units::conversion::*;
+use simple_units::serialization;
+
+fn assert_close(value: f64, expected: f64) {
+    assert!((value - expected).abs() <= 1.0e-12 * expected.abs(), "{} != {}", value, expected);
+}
+
+// Without symbol the type name is used
+#[derive(Debug, Clone, Copy, Unit)]
+struct Widget(f64);
+
+#[derive(Debug, Clone, Copy, Unit)]
+#[unit(symbol = "fur", base = Meter, factor = 201.168)]
+struct Furlong(f64);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Sample {
+    length: Meter,
+    #[serde(with = "serialization::as_string")]
+    temperature: DegC,
+    #[serde(with = "serialization::as_map")]
+    duration: Hour,
+}
+
+#[test]
+fn test_serialize_bare() {
+    assert_eq!(serde_json::to_string(&Meter(1.5)).unwrap(), "1.5");
+    assert_eq!(serde_json::to_string(&Foot(2.0)).unwrap(), "2.0");
+    assert_eq!(serde_json::to_string(&Kelvin(300.0)).unwrap(), "300.0");
+    assert_eq!(serde_json::from_str::<Meter>("1.5").unwrap(), Meter(1.5));
+    assert_eq!(serde_json::from_str::<Foot>("2").unwrap(), Foot(2.0));
+    assert_eq!(serde_json::from_str::<DegC>("10.0").unwrap(), DegC(10.0));
+}
+
+#[test]
+fn test_serialize_self_describing() {
+    let sample = Sample { length: Meter(1.5), temperature: DegC(10.0), duration: Hour(2.0) };
+    let json = serde_json::to_string(&sample).unwrap();
+
+    assert_eq!(json, r#"{"length":1.5,"temperature":"10 degC","duration":{"value":2.0,"unit":"h"}}"#);
+    assert_eq!(serde_json::from_str::<Sample>(&json).unwrap(), sample);
+}
+
+#[test]
+fn test_deserialize_conversion() {
+    assert_close(serde_json::from_str::<Meter>(r#""3 ft""#).unwrap().0, 0.9144);
+    assert_close(serde_json::from_str::<Meter>(r#"{"value": 3.0, "unit": "ft"}"#).unwrap().0, 0.9144);
+    assert_close(serde_json::from_str::<Foot>(r#""0.9144 m""#).unwrap().0, 3.0);
+    assert_close(serde_json::from_str::<JoulePerMol>(r#""105 kJ/mol""#).unwrap().0, 105.0e3);
+    assert_close(serde_json::from_str::<Hour>(r#""30 min""#).unwrap().0, 0.5);
+}
+
+#[test]
+fn test_deserialize_temperature() {
+    assert_close(serde_json::from_str::<Kelvin>(r#""10 degC""#).unwrap().0, 283.15);
+    assert_close(serde_json::from_str::<DegC>(r#""283.15 K""#).unwrap().0, 10.0);
+    assert_close(serde_json::from_str::<Fahrenheit>(r#""100 degC""#).unwrap().0, 212.0);
+    assert_close(serde_json::from_str::<KelvinDelta>(r#""10 degC""#).unwrap().0, 10.0);
+    assert_close(serde_json::from_str::<FahrenheitDelta>(r#""10 K""#).unwrap().0, 18.0);
+}
+
+#[test]
+fn test_deserialize_error() {
+    assert!(serde_json::from_str::<Meter>(r#""3 s""#).is_err());
+    assert!(serde_json::from_str::<Meter>(r#""3 cubits""#).is_err());
+    assert!(serde_json::from_str::<Meter>(r#"{"value": 3.0}"#).is_err());
+    assert!(serde_json::from_str::<Kelvin>(r#"{"value": 3.0, "unit": "m"}"#).is_err());
+    assert!(serde_json::from_str::<Meter>("true").is_err());
+}
+
+#[test]
+fn test_deserialize_kinds() {
+    assert!(serde_json::from_str::<Becquerel>(r#""5 Hz""#).is_err());
+    assert!(serde_json::from_str::<Hertz>(r#"{"value": 5.0, "unit": "Bq"}"#).is_err());
+    assert!(serde_json::from_str::<PerSecond>(r#""5 Hz""#).is_err());
+    assert!(serde_json::from_str::<Sievert>(r#""5 Gy""#).is_err());
+    assert!(serde_json::from_str::<MolPerSecond>(r#""5 kat""#).is_err());
+    assert_eq!(serde_json::from_str::<Becquerel>(r#""5 1/s""#).unwrap(), Becquerel(5.0));
+    assert_eq!(serde_json::from_str::<Hertz>(r#""5 kHz""#).unwrap(), Hertz(5000.0));
+    assert_close(serde_json::from_str::<Radian>(r#""180 deg""#).unwrap().0, ::std::f64::consts::PI);
+}
+
+#[test]
+fn test_derived_unit_round_trip() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Parts {
+        #[serde(with = "serialization::as_string")]
+        widgets: Widget,
+        #[serde(with = "serialization::as_map")]
+        distance: Furlong,
+    }
+
+    let parts = Parts { widgets: Widget(3.0), distance: Furlong(1.5) };
+    let json = serde_json::to_string(&parts).unwrap();
+
+    assert_eq!(json, r#"{"widgets":"3 Widget","distance":{"value":1.5,"unit":"fur"}}"#);
+    assert_eq!(serde_json::from_str::<Parts>(&json).unwrap(), parts);
+    assert_eq!(serde_json::from_str::<Widget>(r#"" 2.5e1  Widget ""#).unwrap(), Widget(25.0));
+    assert!(serde_json::from_str::<Widget>(r#""3 Gadget""#).is_err());
+    assert!(serde_json::from_str::<Widget>(r#""Widget""#).is_err());
+}
+
+#[test]
+fn test_bincode_round_trip() {
+    assert_eq!(bincode::deserialize::<Meter>(&bincode::serialize(&Meter(1.5)).unwrap()).unwrap(), Meter(1.5));
+    assert_eq!(bincode::deserialize::<Foot>(&bincode::serialize(&Foot(2.0)).unwrap()).unwrap(), Foot(2.0));
+    assert_eq!(bincode::deserialize::<DegC>(&bincode::serialize(&DegC(-40.0)).unwrap()).unwrap(), DegC(-40.0));
+
+    let sample = Sample { length: Meter(1.5), temperature: DegC(10.0), duration: Hour(2.0) };
+    let bytes = bincode::serialize(&sample).unwrap();
+    assert_eq!(bytes.len(), 3 * 8);
+    assert_eq!(bincode::deserialize::<Sample>(&bytes).unwrap(), sample);
+}
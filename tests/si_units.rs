@@ -1,6 +1,8 @@
 extern crate simple_units;
 
 use simple_units::si_units::*;
+use simple_units::display::{UnitDisplay, DisplayStyle};
+use simple_units::parse::ParseError;
 
 #[test]
 fn test_meter_add() {
@@ -122,3 +124,115 @@ fn test_meter_per_second_mul_second() {
 fn test_meter_div_meter_per_second() {
     assert_eq!(Meter(10.0) / MeterPerSecond(2.0), Second(5.0));
 }
+
+#[test]
+fn test_ampere() {
+    assert_eq!(Ampere(2.0) * PerAmpere(1.5), 3.0);
+    assert_eq!(1.0 / Ampere(4.0), PerAmpere(0.25));
+    assert_eq!(Ampere(2.0) * Ampere(3.0), Ampere2(6.0));
+    assert_eq!(Ampere(6.0) / Meter(2.0), AmperePerMeter(3.0));
+    assert_eq!(Ampere(6.0) / Meter2(2.0), AmperePerMeter2(3.0));
+    assert_eq!(Ampere(6.0) / Second(2.0), AmperePerSecond(3.0));
+
+    // Power per current and energy per charge have the same dimension (volt)
+    let voltage = Watt(12.0) / Ampere(2.0);
+    assert_eq!(voltage * Ampere(2.0), Watt(12.0));
+    assert_eq!(Joule(12.0) / (Ampere(2.0) * Second(3.0)), voltage / 3.0);
+}
+
+#[test]
+fn test_candela() {
+    assert_eq!(Candela(2.0) * PerCandela(1.5), 3.0);
+    assert_eq!(Candela(6.0) / Meter2(2.0), CandelaPerMeter2(3.0));
+    assert_eq!(CandelaPerMeter2(3.0) * Meter2(2.0), Candela(6.0));
+}
+
+#[test]
+fn test_display_base_units() {
+    assert_eq!(format!("{}", Ampere(1.5)), "1.5 A");
+    assert_eq!(format!("{}", Candela(1.5)), "1.5 cd");
+    assert_eq!("1.5 mA".parse::<Ampere>(), Ok(Ampere(1.5e-3)));
+    assert_eq!("3 cd/m^2".parse::<CandelaPerMeter2>(), Ok(CandelaPerMeter2(3.0)));
+}
+
+#[test]
+fn test_electromagnetic_units() {
+    assert_eq!(Volt(3.0) * Ampere(2.0), Watt(6.0));
+    assert_eq!(Volt(6.0) / Ampere(2.0), Ohm(3.0));
+    assert_eq!(Ampere(6.0) / Volt(2.0), Siemens(3.0));
+    assert_eq!(1.0 / Ohm(4.0), Siemens(0.25));
+    assert_eq!(Coulomb(6.0) / Second(2.0), Ampere(3.0));
+    assert_eq!(Ampere(3.0) * Second(2.0), Coulomb(6.0));
+    assert_eq!(Farad(2.0) * Volt(3.0), Coulomb(6.0));
+    assert_eq!(Coulomb(6.0) / Volt(3.0), Farad(2.0));
+    assert_eq!(Volt(2.0) * Coulomb(3.0), Joule(6.0));
+    assert_eq!(Weber(6.0) / Meter2(2.0), Tesla(3.0));
+    assert_eq!(Tesla(3.0) * Meter2(2.0), Weber(6.0));
+    assert_eq!(Volt(3.0) * Second(2.0), Weber(6.0));
+    assert_eq!(Weber(6.0) / Ampere(2.0), Henry(3.0));
+    assert_eq!(Henry(2.0) / Second(1.0), Ohm(2.0));
+    assert_eq!(Ohm(2.0) * Farad(3.0), Second(6.0));
+    assert_eq!(Volt(6.0) / Meter(2.0), VoltPerMeter(3.0));
+    assert_eq!(Tesla(2.0) * Ampere(3.0) * Meter(1.0), Newton(6.0));
+}
+
+#[test]
+fn test_display_electromagnetic_units() {
+    assert_eq!(format!("{}", Ohm(1.5)), "1.5 Ω");
+    assert_eq!(format!("{}", Volt(1.5).display_as(DisplayStyle::Ascii)), "1.5 V");
+    assert_eq!(format!("{}", Tesla(1.5).display_as(DisplayStyle::Latex)), "\\SI{1.5}{\\tesla}");
+    assert_eq!("2 kOhm".parse::<Ohm>(), Ok(Ohm(2000.0)));
+    assert_eq!("2 kΩ".parse::<Ohm>(), Ok(Ohm(2000.0)));
+    assert_eq!("2 V/A".parse::<Ohm>(), Ok(Ohm(2.0)));
+    assert_eq!("3 C".parse::<Coulomb>(), Ok(Coulomb(3.0)));
+    assert_eq!("3 A*s".parse::<Coulomb>(), Ok(Coulomb(3.0)));
+    assert_eq!("5 mT".parse::<Tesla>(), Ok(Tesla(5.0e-3)));
+    assert_eq!("4 Wb/m^2".parse::<Tesla>(), Ok(Tesla(4.0)));
+}
+
+#[test]
+fn test_frequency_units() {
+    assert_eq!(Hertz::from(PerSecond(50.0)), Hertz(50.0));
+    assert_eq!(PerSecond::from(Becquerel(3.0)), PerSecond(3.0));
+    assert_eq!(Becquerel::from(PerSecond::from(Hertz(2.0))), Becquerel(2.0));
+    assert_eq!(RadianPerSecond::from(1.0 / Second(0.5)), RadianPerSecond(2.0));
+    assert_eq!(RadianPerSecond(2.0) * Second(3.0), Radian(6.0));
+    assert_eq!(Radian(6.0) / Second(3.0), RadianPerSecond(2.0));
+    assert_eq!(Radian(6.0) / RadianPerSecond(2.0), Second(3.0));
+}
+
+#[test]
+fn test_dose_units() {
+    assert_eq!(Gray::from(Joule(6.0) / Kilogram(2.0)), Gray(3.0));
+    assert_eq!(JoulePerKilogram::from(Sievert(3.0)) * Kilogram(2.0), Joule(6.0));
+    assert_eq!(Katal::from(Mol(6.0) / Second(2.0)), Katal(3.0));
+    assert_eq!(MolPerSecond::from(Katal(3.0)), MolPerSecond(3.0));
+    assert_eq!(format!("{}", Gray(1.5)), "1.5 Gy");
+    assert_eq!(format!("{}", RadianPerSecond(1.5)), "1.5 rad·s⁻¹");
+    assert_eq!("2 kHz".parse::<Hertz>(), Ok(Hertz(2000.0)));
+    assert_eq!("5 mSv".parse::<Sievert>(), Ok(Sievert(5.0e-3)));
+}
+
+#[test]
+fn test_parse_frequency_and_dose_kinds() {
+    assert_eq!("5 1/s".parse::<Hertz>(), Ok(Hertz(5.0)));
+    assert_eq!("5 s^-1".parse::<Becquerel>(), Ok(Becquerel(5.0)));
+    assert_eq!("5 J/kg".parse::<Gray>(), Ok(Gray(5.0)));
+    assert_eq!("5 rad/s".parse::<RadianPerSecond>(), Ok(RadianPerSecond(5.0)));
+    assert_eq!("5 Bq".parse::<Hertz>(), Err(ParseError::UnknownUnit("Bq".to_string())));
+    assert_eq!("5 kHz".parse::<Becquerel>(), Err(ParseError::UnknownUnit("kHz".to_string())));
+    assert_eq!("5 Sv".parse::<Gray>(), Err(ParseError::UnknownUnit("Sv".to_string())));
+    assert_eq!("5 Gy".parse::<Sievert>(), Err(ParseError::UnknownUnit("Gy".to_string())));
+    assert_eq!("5 Hz".parse::<RadianPerSecond>(), Err(ParseError::UnknownUnit("Hz".to_string())));
+    assert_eq!("5 rad/s".parse::<Hertz>(), Err(ParseError::UnknownUnit("rad".to_string())));
+    assert!("5 m".parse::<Hertz>().is_err());
+    assert_eq!("5 deg/s".parse::<RadianPerSecond>().unwrap(), RadianPerSecond(5.0f64.to_radians()));
+
+    assert_eq!("5 kat".parse::<Katal>(), Ok(Katal(5.0)));
+    assert_eq!("5 mol/s".parse::<Katal>(), Ok(Katal(5.0)));
+    assert_eq!("5 kat".parse::<MolPerSecond>(), Err(ParseError::UnknownUnit("kat".to_string())));
+    assert_eq!("5 Hz".parse::<PerSecond>(), Err(ParseError::UnknownUnit("Hz".to_string())));
+    assert_eq!("5 mGy".parse::<JoulePerKilogram>(), Err(ParseError::UnknownUnit("mGy".to_string())));
+    assert_eq!("5 rad".parse::<Unitless>(), Err(ParseError::UnknownUnit("rad".to_string())));
+    assert_eq!("5 1/s".parse::<PerSecond>(), Ok(PerSecond(5.0)));
+}